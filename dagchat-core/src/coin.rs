@@ -0,0 +1,35 @@
+use crate::crypto::workcache::WorkCache;
+use crate::network::Network;
+
+#[derive(Debug, Clone)]
+pub struct Coin {
+    pub prefix: String,
+    pub name: String,
+    pub ticker: String,
+    pub multiplier: String,
+    pub network: Network,
+    pub work_cache: WorkCache,
+}
+
+impl Coin {
+    pub fn nano() -> Coin {
+        Coin {
+            prefix: String::from("nano_"),
+            name: String::from("nano"),
+            ticker: String::from("Ӿ"),
+            multiplier: String::from("1000000000000000000000000000000"),
+            network: Network::nano(),
+            work_cache: WorkCache::default(),
+        }
+    }
+    pub fn banano() -> Coin {
+        Coin {
+            prefix: String::from("ban_"),
+            name: String::from("banano"),
+            ticker: String::from(" BAN"),
+            multiplier: String::from("100000000000000000000000000000"),
+            network: Network::banano(),
+            work_cache: WorkCache::default(),
+        }
+    }
+}
@@ -0,0 +1,10 @@
+pub const BANANO_MESSAGE_PREAMBLE: &[u8; 10] = &[98, 97, 110, 97, 110, 111, 109, 115, 103, 45]; //utf-8 "bananomsg-" to hex
+pub const NANO_MESSAGE_PREAMBLE: &[u8; 8] = &[110, 97, 110, 111, 109, 115, 103, 45]; //utf-8 "nanomsg-" to hex
+
+// In seconds. Used as default timeout.
+pub const REQ_TIMEOUT: u64 = 10;
+
+pub mod crypto {
+    pub const SALT_LENGTH: usize = 16;
+    pub const IV_LENGTH: usize = 12;
+}
@@ -31,17 +31,18 @@ pub fn get_address(pub_key_bytes: &[u8], prefix: Option<&str>) -> String {
 // like get_num_equivalent but this is faster since to_public_key
 // is used on confirmed valid addresses frequently in lib
 pub fn validate_address(addr: &str) -> bool {
-    if !addr.contains('_') {
-        return false;
+    let encoded = match addr.split_once('_') {
+        Some((_, encoded)) => encoded,
+        None => return false,
     };
-    let parts: Vec<&str> = addr.split('_').collect();
 
-    // Minimum viable public representation
-    if parts[1].len() < 52 {
+    // Minimum viable public representation. Anything typed in may hold
+    // multibyte characters, which no address has.
+    if !encoded.is_ascii() || encoded.len() < 52 {
         return false;
     };
-    let checksum = String::from(parts[1].get(52..).unwrap());
-    let mut encoded_addr = String::from(parts[1].get(0..52).unwrap());
+    let checksum = String::from(&encoded[52..]);
+    let mut encoded_addr = String::from(&encoded[..52]);
     encoded_addr.insert_str(0, "1111");
 
     let pub_key_vec = ADDR_ENCODING.decode(encoded_addr.as_bytes());
@@ -1,4 +1,5 @@
-use crate::app::constants::crypto::{IV_LENGTH, SALT_LENGTH};
+use crate::constants::crypto::{IV_LENGTH, SALT_LENGTH};
+use crate::error::{Error, Result};
 use aes_gcm::aead::{generic_array::GenericArray, Aead, NewAead};
 use aes_gcm::{Aes256Gcm, Nonce};
 use argon2::{self, Config};
@@ -11,7 +12,7 @@ pub fn derive_key(password: &str, salt: &[u8]) -> Vec<u8> {
     hash
 }
 
-pub fn decrypt_bytes(encrypted_bytes: &[u8], password: &str) -> Result<Vec<u8>, String> {
+pub fn decrypt_bytes(encrypted_bytes: &[u8], password: &str) -> Result<Vec<u8>> {
     let salt = &encrypted_bytes[..SALT_LENGTH];
 
     let key_bytes = derive_key(password, salt);
@@ -23,7 +24,10 @@ pub fn decrypt_bytes(encrypted_bytes: &[u8], password: &str) -> Result<Vec<u8>,
     let decrypted = aead.decrypt(nonce, encrypted);
     match decrypted {
         Ok(decrypted) => Ok(decrypted),
-        Err(e) => Err(format!("Failed to decrypt bytes. Error: {}", e)),
+        Err(e) => Err(Error::Crypto(format!(
+            "Failed to decrypt bytes. Error: {}",
+            e
+        ))),
     }
 }
 
@@ -0,0 +1,327 @@
+use super::keys::{PublicKey, SecretKey};
+use super::pow::*;
+use crate::coin::Coin;
+use crate::error::{Error, Result};
+use crate::network::{Network, WorkType};
+use crate::rpc::blockinfo::Block;
+use crate::rpc::workgenerate::get_server_work;
+use blake2::digest::{Update, VariableOutput};
+use blake2::Blake2bVar;
+use ed25519_dalek::Verifier;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+const STATE_BLOCK_PREAMBLE: [u8; 32] = {
+    let mut preamble = [0u8; 32];
+    preamble[31] = 6;
+    preamble
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Hash(pub [u8; 32]);
+
+impl Hash {
+    pub fn from_hex(hash: &str) -> Result<Hash> {
+        let bytes = hex::decode(hash).map_err(|e| Error::Decode(e.to_string()))?;
+        let bytes: [u8; 32] = bytes
+            .try_into()
+            .map_err(|_| Error::Decode(format!("hash was not 32 bytes: {}", hash)))?;
+        Ok(Hash(bytes))
+    }
+
+    pub fn to_hex(&self) -> String {
+        hex::encode(self.0)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0 == [0u8; 32]
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+impl From<PublicKey> for Hash {
+    fn from(public_key: PublicKey) -> Hash {
+        Hash(public_key.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Amount(pub u128);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Subtype {
+    Send,
+    Receive,
+    Change,
+}
+
+impl Subtype {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Subtype::Send => "send",
+            Subtype::Receive => "receive",
+            Subtype::Change => "change",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StateBlock {
+    pub subtype: Subtype,
+    pub account: PublicKey,
+    // Zero for the open block of an account.
+    pub previous: Hash,
+    pub representative: PublicKey,
+    pub balance: Amount,
+    // Destination public key for sends, source block hash for
+    // receives and zero for representative changes.
+    pub link: Hash,
+    pub signature: Option<[u8; 64]>,
+    pub work: Option<u64>,
+}
+
+impl StateBlock {
+    pub fn new(
+        subtype: Subtype,
+        account: PublicKey,
+        previous: Hash,
+        representative: PublicKey,
+        balance: Amount,
+        link: Hash,
+    ) -> StateBlock {
+        StateBlock {
+            subtype,
+            account,
+            previous,
+            representative,
+            balance,
+            link,
+            signature: None,
+            work: None,
+        }
+    }
+
+    pub fn hash(&self) -> Hash {
+        let mut hasher = Blake2bVar::new(32).unwrap();
+        let mut buf = [0u8; 32];
+        hasher.update(&STATE_BLOCK_PREAMBLE);
+        hasher.update(self.account.as_bytes());
+        hasher.update(self.previous.as_bytes());
+        hasher.update(self.representative.as_bytes());
+        hasher.update(&self.balance.0.to_be_bytes());
+        hasher.update(self.link.as_bytes());
+        hasher.finalize_variable(&mut buf).unwrap();
+        Hash(buf)
+    }
+
+    // The hash that proof of work is computed against: the previous
+    // block, or the account's public key for an open block.
+    pub fn work_root(&self) -> Hash {
+        if self.previous.is_zero() {
+            Hash::from(self.account)
+        } else {
+            self.previous
+        }
+    }
+
+    pub fn work_threshold<'a>(&self, network: &'a Network) -> &'a str {
+        if self.subtype == Subtype::Receive {
+            &network.receive_thresh
+        } else {
+            &network.send_thresh
+        }
+    }
+
+    pub fn has_valid_work(&self, network: &Network) -> bool {
+        match (
+            self.work,
+            u64::from_str_radix(self.work_threshold(network), 16),
+        ) {
+            (Some(work), Ok(threshold)) => {
+                validate_work(self.work_root().as_bytes(), work, threshold)
+            }
+            _ => false,
+        }
+    }
+
+    pub fn sign(&mut self, private_key: &SecretKey) {
+        let secret = ed25519_dalek::SecretKey::from_bytes(private_key.as_bytes()).unwrap();
+        let public = ed25519_dalek::PublicKey::from(&secret);
+        let expanded_secret = ed25519_dalek::ExpandedSecretKey::from(&secret);
+        let signature = expanded_secret.sign(self.hash().as_bytes(), &public);
+        self.signature = Some(signature.to_bytes());
+    }
+
+    pub fn verify_signature(&self) -> bool {
+        let signature = match self.signature {
+            Some(signature) => signature,
+            None => return false,
+        };
+        let public = match ed25519_dalek::PublicKey::from_bytes(self.account.as_bytes()) {
+            Ok(public) => public,
+            Err(_) => return false,
+        };
+        let signature = match ed25519_dalek::Signature::from_bytes(&signature) {
+            Ok(signature) => signature,
+            Err(_) => return false,
+        };
+        public.verify(self.hash().as_bytes(), &signature).is_ok()
+    }
+
+    pub fn to_block(&self, prefix: &str) -> Block {
+        Block {
+            type_name: String::from("state"),
+            account: self.account.to_address(prefix),
+            previous: self.previous.to_hex(),
+            representative: self.representative.to_address(prefix),
+            balance: self.balance.0.to_string(),
+            link: self.link.to_hex(),
+            work: match self.work {
+                Some(work) => format!("{:016x}", work),
+                None => String::from(""),
+            },
+            signature: match self.signature {
+                Some(signature) => hex::encode(signature),
+                None => String::from(""),
+            },
+        }
+    }
+
+    // The subtype isn't part of a block's contents, so it has to be
+    // supplied by whoever knows where the block came from.
+    pub fn from_block(block: &Block, subtype: Subtype) -> Result<StateBlock> {
+        if block.type_name != "state" {
+            return Err(Error::Decode(format!(
+                "expected a state block, got: {}",
+                block.type_name
+            )));
+        }
+        let balance = block
+            .balance
+            .parse()
+            .map_err(|_| Error::Decode(format!("invalid balance: {}", block.balance)))?;
+        let signature = if block.signature.is_empty() {
+            None
+        } else {
+            let bytes = hex::decode(&block.signature).map_err(|e| Error::Decode(e.to_string()))?;
+            let bytes: [u8; 64] = bytes.try_into().map_err(|_| {
+                Error::Decode(format!("signature was not 64 bytes: {}", block.signature))
+            })?;
+            Some(bytes)
+        };
+        let work = if block.work.is_empty() {
+            None
+        } else {
+            Some(
+                u64::from_str_radix(&block.work, 16)
+                    .map_err(|_| Error::Decode(format!("invalid work: {}", block.work)))?,
+            )
+        };
+        Ok(StateBlock {
+            subtype,
+            account: PublicKey::from_address(&block.account)?,
+            previous: Hash::from_hex(&block.previous)?,
+            representative: PublicKey::from_address(&block.representative)?,
+            balance: Amount(balance),
+            link: Hash::from_hex(&block.link)?,
+            signature,
+            work,
+        })
+    }
+
+    pub fn to_rpc_json(&self, prefix: &str) -> Value {
+        serde_json::to_value(self.to_block(prefix)).unwrap()
+    }
+
+    // Parses a block fetched from a node and rejects it unless it
+    // carries a valid signature from the account it claims.
+    pub fn from_signed_block(block: &Block, subtype: Subtype) -> Result<StateBlock> {
+        let state_block = StateBlock::from_block(block, subtype)?;
+        if !state_block.verify_signature() {
+            return Err(Error::Tampered(format!(
+                "block {} has an invalid signature for {}",
+                state_block.hash().to_hex(),
+                block.account
+            )));
+        }
+        Ok(state_block)
+    }
+}
+
+// Signs the block and attaches proof of work from the source
+// configured for the coin's network. Nothing is published here, so
+// cancelling through the control leaves the account untouched.
+pub fn get_signed_block(
+    mut block: StateBlock,
+    private_key: &SecretKey,
+    coin: &Coin,
+    control: &WorkControl,
+) -> Result<StateBlock> {
+    if control.is_cancelled() {
+        return Err(Error::Cancelled);
+    }
+    block.sign(private_key);
+    attach_work(block, coin, control)
+}
+
+// Attaches proof of work from the source configured for the coin's
+// network to an already signed block. The work isn't covered by the
+// signature, so this can happen on a different device to the signing.
+pub fn attach_work(
+    mut block: StateBlock,
+    coin: &Coin,
+    control: &WorkControl,
+) -> Result<StateBlock> {
+    if control.is_cancelled() {
+        return Err(Error::Cancelled);
+    }
+    let work_type = coin.network.work_type;
+    if work_type != WorkType::BOOMPOW {
+        let root = block.work_root();
+        if let Some(work) = coin.work_cache.get(&root) {
+            // Cached work is spent either way: it's used now or it no
+            // longer meets the network's thresholds.
+            coin.work_cache.remove(&root);
+            block.work = Some(work);
+            if block.has_valid_work(&coin.network) {
+                return Ok(block);
+            }
+        }
+    }
+    if work_type == WorkType::CPU {
+        block.work = Some(cpu_work(&block, &coin.network, control)?);
+    } else if work_type == WorkType::WORK_SERVER {
+        let root = block.work_root();
+        let threshold = block.work_threshold(&coin.network);
+        let work = get_server_work(root.as_bytes(), threshold, &coin.network.work_server_url)?;
+        if control.is_cancelled() {
+            return Err(Error::Cancelled);
+        }
+        block.work = Some(parse_work(&work)?);
+        // A faulty work server shouldn't cost us a rejected block.
+        if !block.has_valid_work(&coin.network) {
+            block.work = Some(cpu_work(&block, &coin.network, control)?);
+        }
+    } else if work_type == WorkType::BOOMPOW {
+        block.work = None;
+    } else {
+        panic!("Unknown network WorkType.");
+    }
+    Ok(block)
+}
+
+fn cpu_work(block: &StateBlock, network: &Network, control: &WorkControl) -> Result<u64> {
+    // If it is the open block then use the public key to generate work.
+    // If not, use previous block hash.
+    let root = block.work_root();
+    let threshold = u64::from_str_radix(block.work_threshold(network), 16).unwrap();
+    parse_work(&generate_work(root.as_bytes(), threshold, control)?)
+}
+
+fn parse_work(work: &str) -> Result<u64> {
+    u64::from_str_radix(work, 16).map_err(|_| Error::Work(format!("invalid work: {}", work)))
+}
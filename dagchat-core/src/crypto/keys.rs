@@ -0,0 +1,122 @@
+use super::address::{get_address, validate_address, ADDR_ENCODING};
+use crate::error::{Error, Result};
+use blake2::digest::{Update, VariableOutput};
+use blake2::Blake2bVar;
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha512;
+
+const HARDENED: u32 = 0x8000_0000;
+
+// How a wallet's accounts are derived from its seed.
+pub struct Derivation;
+impl Derivation {
+    // blake2b(seed || index), used by nano's own wallets.
+    pub const LEGACY: usize = 0;
+    // SLIP-10 ed25519 along m/44'/coin type'/index', used by Ledger and
+    // most wallets that take BIP39 mnemonics.
+    pub const BIP44: usize = 1;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PublicKey(pub [u8; 32]);
+
+impl PublicKey {
+    pub fn from_address(addr: &str) -> Result<PublicKey> {
+        if !validate_address(addr) {
+            return Err(Error::Decode(format!("invalid address: {}", addr)));
+        }
+        Ok(PublicKey(to_public_key(addr)))
+    }
+
+    pub fn to_address(&self, prefix: &str) -> String {
+        get_address(&self.0, Some(prefix))
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+// Deliberately not Debug so that private keys don't end up in logs.
+#[derive(Clone, Copy)]
+pub struct SecretKey(pub [u8; 32]);
+
+impl SecretKey {
+    pub fn public_key(&self) -> PublicKey {
+        let secret = ed25519_dalek::SecretKey::from_bytes(&self.0).unwrap();
+        PublicKey(ed25519_dalek::PublicKey::from(&secret).to_bytes())
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+// Legacy nano derivation. The seed is 32 bytes, or the 64 byte seed of
+// a BIP39 mnemonic.
+pub fn get_private_key(seed_bytes: &[u8], idx: u32) -> [u8; 32] {
+    let mut hasher = Blake2bVar::new(32).unwrap();
+    let mut buf = [0u8; 32];
+    hasher.update(seed_bytes);
+    hasher.update(&idx.to_be_bytes());
+    hasher.finalize_variable(&mut buf).unwrap();
+    buf
+}
+
+// SLIP-44 coin types.
+pub fn coin_type(prefix: &str) -> u32 {
+    if prefix == "ban_" {
+        198
+    } else {
+        165
+    }
+}
+
+pub fn derive_private_key(
+    seed_bytes: &[u8],
+    idx: u32,
+    derivation: usize,
+    prefix: &str,
+) -> [u8; 32] {
+    if derivation == Derivation::BIP44 {
+        get_slip10_private_key(seed_bytes, &[44, coin_type(prefix), idx])
+    } else {
+        get_private_key(seed_bytes, idx)
+    }
+}
+
+// ed25519 only supports hardened children, so every index in the path
+// is hardened.
+pub fn get_slip10_private_key(seed_bytes: &[u8], path: &[u32]) -> [u8; 32] {
+    let (mut key, mut chain_code) = slip10_split(b"ed25519 seed", &[seed_bytes]);
+    for idx in path {
+        (key, chain_code) = slip10_split(
+            &chain_code,
+            &[&[0u8], &key, &(idx | HARDENED).to_be_bytes()],
+        );
+    }
+    key
+}
+
+fn slip10_split(hmac_key: &[u8], data: &[&[u8]]) -> ([u8; 32], [u8; 32]) {
+    let mut mac = Hmac::<Sha512>::new_from_slice(hmac_key).unwrap();
+    for part in data {
+        Mac::update(&mut mac, part);
+    }
+    let result = mac.finalize().into_bytes();
+    let mut key = [0u8; 32];
+    let mut chain_code = [0u8; 32];
+    key.copy_from_slice(&result[..32]);
+    chain_code.copy_from_slice(&result[32..]);
+    (key, chain_code)
+}
+
+pub fn to_public_key(addr: &str) -> [u8; 32] {
+    let parts: Vec<&str> = addr.split('_').collect();
+    let mut encoded_addr = String::from(parts[1].get(0..52).unwrap());
+    encoded_addr.insert_str(0, "1111");
+    let mut pub_key_vec = ADDR_ENCODING.decode(encoded_addr.as_bytes()).unwrap();
+    pub_key_vec.drain(0..3);
+    pub_key_vec.as_slice().try_into().unwrap()
+}
@@ -0,0 +1,239 @@
+use super::wordlist::WORD_LIST;
+use bitreader::BitReader;
+use hmac::Hmac;
+use sha2::{Digest, Sha256, Sha512};
+use unicode_normalization::UnicodeNormalization;
+
+// Every mnemonic length BIP39 allows. Each word is 11 bits, of which
+// one in every 33 is checksum: 128 to 256 bits of entropy.
+pub const WORD_COUNTS: [usize; 5] = [12, 15, 18, 21, 24];
+const PBKDF2_ROUNDS: u32 = 2048;
+
+// Legacy nano mnemonics are the 24 word encoding of the seed itself.
+pub fn validate_mnemonic(mnemonic: &str) -> Option<[u8; 32]> {
+    mnemonic_to_entropy(mnemonic)?.try_into().ok()
+}
+
+pub fn mnemonic_to_entropy(mnemonic: &str) -> Option<Vec<u8>> {
+    let (mnemonic, valid) = get_num_equivalent(mnemonic);
+    if !valid {
+        return None;
+    }
+    let total_bits = mnemonic.len() * 11;
+    let checksum_bits = total_bits / 33;
+    let entropy_bits = total_bits - checksum_bits;
+    let mut bits = vec![false; total_bits];
+    for i in 0..mnemonic.len() {
+        for j in 0..11 {
+            bits[i * 11 + j] = mnemonic[i] >> (10 - j) & 1 == 1;
+        }
+    }
+
+    let mut entropy = vec![0u8; entropy_bits / 8];
+    for i in 0..entropy.len() {
+        for j in 0..8 {
+            if bits[i * 8 + j] {
+                entropy[i] += 1 << (7 - j);
+            }
+        }
+    }
+
+    let mut hasher = Sha256::new();
+    Digest::update(&mut hasher, &entropy);
+    let check = hasher.finalize();
+    for i in 0..checksum_bits {
+        if bits[entropy_bits + i] != ((check[i / 8] & (1 << (7 - (i % 8)))) > 0) {
+            return None;
+        }
+    }
+
+    Some(entropy)
+}
+
+// Takes 16 to 32 bytes of entropy, in steps of 4.
+pub fn seed_to_mnemonic(seed_bytes: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    Digest::update(&mut hasher, seed_bytes);
+    let check = hasher.finalize();
+
+    let mut combined = Vec::from(seed_bytes);
+    combined.extend(&check);
+
+    let mut reader = BitReader::new(&combined);
+
+    let mut words: Vec<&str> = Vec::new();
+    for _ in 0..(seed_bytes.len() * 3 / 4) {
+        let n = reader.read_u16(11);
+        words.push(WORD_LIST[n.unwrap() as usize].as_ref());
+    }
+    words.join(" ")
+}
+
+// The 64 byte BIP39 seed. Unlike legacy nano mnemonics this isn't the
+// entropy, so the same phrase gives a different wallet for every
+// passphrase, including the empty one.
+pub fn mnemonic_to_seed(mnemonic: &str, passphrase: &str) -> [u8; 64] {
+    let mnemonic: String = mnemonic
+        .split_whitespace()
+        .collect::<Vec<&str>>()
+        .join(" ")
+        .nfkd()
+        .collect();
+    let salt: String = format!("mnemonic{}", passphrase).nfkd().collect();
+    let mut seed = [0u8; 64];
+    pbkdf2::pbkdf2::<Hmac<Sha512>>(
+        mnemonic.as_bytes(),
+        salt.as_bytes(),
+        PBKDF2_ROUNDS,
+        &mut seed,
+    );
+    seed
+}
+
+pub fn wordlist_position(word: &str) -> u16 {
+    let index = WORD_LIST.iter().position(|&w| w == word).unwrap();
+    index as u16
+}
+
+pub fn get_num_equivalent(mnemonic: &str) -> (Vec<u16>, bool) {
+    let mut num_mnemonic: Vec<u16> = vec![];
+    let words: Vec<&str> = mnemonic.split_whitespace().collect();
+    if !WORD_COUNTS.contains(&words.len()) {
+        return (num_mnemonic, false);
+    }
+    for word in words {
+        if WORD_LIST.contains(&word) {
+            num_mnemonic.push(wordlist_position(word));
+        } else {
+            return (num_mnemonic, false);
+        }
+    }
+    (num_mnemonic, true)
+}
+
+// Suggestions are limited to words this close to what was typed.
+const MAX_EDIT_DISTANCE: usize = 2;
+const MAX_SUGGESTIONS: usize = 5;
+// BIP39 words are unique in their first four letters.
+const PREFIX_LENGTH: usize = 4;
+
+pub struct UnknownWord {
+    // Counting from 1, as the words are written down.
+    pub position: usize,
+    pub word: String,
+    pub suggestions: Vec<&'static str>,
+}
+
+pub fn unknown_words(mnemonic: &str) -> Vec<UnknownWord> {
+    mnemonic
+        .split_whitespace()
+        .enumerate()
+        .filter(|(_, word)| !WORD_LIST.contains(word))
+        .map(|(i, word)| UnknownWord {
+            position: i + 1,
+            word: String::from(word),
+            suggestions: suggest_words(word),
+        })
+        .collect()
+}
+
+// The word sharing its first four letters, then the closest words by
+// edit distance.
+pub fn suggest_words(word: &str) -> Vec<&'static str> {
+    let mut suggestions = vec![];
+    if let Some(prefix) = prefix(word) {
+        let matches = complete_word(&prefix);
+        if matches.len() == 1 {
+            suggestions.push(matches[0]);
+        }
+    }
+    for candidate in nearby_words(word, MAX_EDIT_DISTANCE) {
+        if suggestions.len() == MAX_SUGGESTIONS {
+            break;
+        }
+        if !suggestions.contains(&candidate) {
+            suggestions.push(candidate);
+        }
+    }
+    suggestions
+}
+
+pub fn complete_word(prefix: &str) -> Vec<&'static str> {
+    WORD_LIST
+        .iter()
+        .filter(|word| word.starts_with(prefix))
+        .copied()
+        .collect()
+}
+
+// Every way of replacing a single word so that the checksum passes,
+// as the position of the word (counting from 1) and its replacement.
+// An unknown word is the only one tried if there is one, otherwise
+// each word is tried against its closest neighbours.
+pub fn single_word_corrections(mnemonic: &str) -> Vec<(usize, &'static str)> {
+    let words: Vec<&str> = mnemonic.split_whitespace().collect();
+    if !WORD_COUNTS.contains(&words.len()) {
+        return vec![];
+    }
+    let unknown: Vec<usize> = (0..words.len())
+        .filter(|&i| !WORD_LIST.contains(&words[i]))
+        .collect();
+    let (positions, distance) = match unknown.len() {
+        0 => ((0..words.len()).collect(), 1),
+        1 => (unknown, MAX_EDIT_DISTANCE),
+        _ => return vec![],
+    };
+    let mut corrections = vec![];
+    for i in positions {
+        let mut candidates = nearby_words(words[i], distance);
+        if let Some(prefix) = prefix(words[i]) {
+            candidates.extend(complete_word(&prefix));
+        }
+        for candidate in candidates {
+            if candidate == words[i] || corrections.contains(&(i + 1, candidate)) {
+                continue;
+            }
+            let mut corrected = words.clone();
+            corrected[i] = candidate;
+            if mnemonic_to_entropy(&corrected.join(" ")).is_some() {
+                corrections.push((i + 1, candidate));
+            }
+        }
+    }
+    corrections
+}
+
+// The word's first four letters, taken by character as it may be
+// anything typed in by the user.
+fn prefix(word: &str) -> Option<String> {
+    if word.chars().count() < PREFIX_LENGTH {
+        return None;
+    }
+    Some(word.chars().take(PREFIX_LENGTH).collect())
+}
+
+// Words within max_distance edits of word, closest first.
+fn nearby_words(word: &str, max_distance: usize) -> Vec<&'static str> {
+    let mut nearby: Vec<(usize, &'static str)> = WORD_LIST
+        .iter()
+        .map(|candidate| (edit_distance(word, candidate), *candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .collect();
+    nearby.sort_by_key(|(distance, _)| *distance);
+    nearby.into_iter().map(|(_, candidate)| candidate).collect()
+}
+
+// Levenshtein distance.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
@@ -5,5 +5,8 @@ pub mod conversions;
 pub mod keys;
 pub mod mnemonic;
 pub mod pow;
+pub mod shamir;
 pub mod signmessage;
+pub mod vanity;
 pub mod wordlist;
+pub mod workcache;
@@ -0,0 +1,255 @@
+use crate::error::{Error, Result};
+use rand::RngCore;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+pub(crate) type Terminated = Arc<AtomicBool>;
+pub(crate) type Attempts = Arc<AtomicU64>;
+
+// How often the coordinating thread checks for cancellation and
+// updates the hashrate.
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+const ATTEMPTS_PER_BATCH: u64 = 4096;
+
+// Lets another thread cancel work generation and watch its progress.
+// Clones share the same state.
+#[derive(Debug, Clone, Default)]
+pub struct WorkControl {
+    cancelled: Arc<AtomicBool>,
+    hashrate: Arc<AtomicU64>,
+}
+
+impl WorkControl {
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    // Attempts per second across all worker threads, or zero when no
+    // work is being generated locally.
+    pub fn hashrate(&self) -> u64 {
+        self.hashrate.load(Ordering::Relaxed)
+    }
+}
+
+pub fn generate_work(
+    input_hash: &[u8; 32],
+    threshold: u64,
+    control: &WorkControl,
+) -> Result<String> {
+    let (nonce, _, _) = run_workers(input_hash, threshold, control, None);
+    match nonce {
+        // Work is written as the nonce's bytes reversed, i.e. big-endian.
+        Some(nonce) => Ok(format!("{:016x}", nonce)),
+        None => Err(Error::Cancelled),
+    }
+}
+
+// Measures how many attempts per second this device manages with
+// every core searching, over roughly the given duration.
+pub fn benchmark(duration: Duration, control: &WorkControl) -> u64 {
+    let mut root = [0u8; 32];
+    rand::thread_rng().fill_bytes(&mut root);
+    // No work can beat the maximum threshold, so the search runs for
+    // the whole duration.
+    let (_, attempts, elapsed) = run_workers(&root, u64::MAX, control, Some(duration));
+    (attempts as f64 / elapsed) as u64
+}
+
+// Average number of attempts needed to find work for a threshold.
+pub fn expected_attempts(threshold: u64) -> f64 {
+    2f64.powi(64) / ((u64::MAX - threshold) as f64 + 1.0)
+}
+
+// Runs a search for work on every core until it is found, the control
+// is cancelled or the time limit passes. Returns the nonce if one was
+// found, along with the attempts made and seconds taken.
+fn run_workers(
+    input_hash: &[u8; 32],
+    threshold: u64,
+    control: &WorkControl,
+    limit: Option<Duration>,
+) -> (Option<u64>, u64, f64) {
+    // Each thread scans its own slice of the nonce space, starting from
+    // a random point so that repeated searches don't overlap.
+    let input_copy = *input_hash;
+    let base = rand::thread_rng().next_u64();
+    let stride = u64::MAX / num_cpus::get() as u64;
+    run_threads(control, limit, &|_| {}, move |i, terminator, counter| {
+        let start = base.wrapping_add(i * stride);
+        compute_work(terminator, counter, &input_copy, threshold, start)
+    })
+}
+
+// Runs search on every core until one of them finds a result, the
+// control is cancelled or the time limit passes. Searches are given
+// the index of their thread, and should count their attempts and
+// return None once terminated. Progress is told the attempts so far
+// as they are polled.
+pub(crate) fn run_threads<T, F>(
+    control: &WorkControl,
+    limit: Option<Duration>,
+    progress: &dyn Fn(u64),
+    search: F,
+) -> (Option<T>, u64, f64)
+where
+    T: Send + 'static,
+    F: Fn(u64, Terminated, Attempts) -> Option<T> + Send + Sync + 'static,
+{
+    let cpus = num_cpus::get() as u64;
+    let terminated = Arc::new(AtomicBool::new(false));
+    let attempts = Arc::new(AtomicU64::new(0));
+    let search = Arc::new(search);
+    let mut threads = vec![];
+
+    for i in 0..cpus {
+        let terminator = terminated.clone();
+        let counter = attempts.clone();
+        let search = search.clone();
+        let thread_handle = thread::spawn(move || search(i, terminator, counter));
+        threads.push(thread_handle);
+    }
+
+    let start = Instant::now();
+    while !terminated.load(Ordering::Relaxed) {
+        let timed_out = limit.is_some_and(|limit| start.elapsed() >= limit);
+        if control.is_cancelled() || timed_out {
+            terminated.store(true, Ordering::Relaxed);
+            break;
+        }
+        thread::sleep(POLL_INTERVAL);
+        let elapsed = start.elapsed().as_secs_f64();
+        let made = attempts.load(Ordering::Relaxed);
+        control
+            .hashrate
+            .store((made as f64 / elapsed) as u64, Ordering::Relaxed);
+        progress(made);
+    }
+    control.hashrate.store(0, Ordering::Relaxed);
+
+    let mut result = None;
+    for thread in threads.into_iter() {
+        if let Some(found) = thread.join().unwrap() {
+            result = Some(found);
+        }
+    }
+    let elapsed = start.elapsed().as_secs_f64();
+    (result, attempts.load(Ordering::Relaxed), elapsed)
+}
+
+fn compute_work(
+    terminated: Terminated,
+    attempts: Attempts,
+    input_hash: &[u8; 32],
+    threshold: u64,
+    start: u64,
+) -> Option<u64> {
+    let mut hasher = WorkHasher::new(input_hash);
+    let mut nonce = start;
+    loop {
+        // Batched so the shared counter isn't contended every attempt.
+        for _ in 0..ATTEMPTS_PER_BATCH {
+            if hasher.difficulty(nonce) >= threshold {
+                terminated.store(true, Ordering::Relaxed);
+                return Some(nonce);
+            }
+            nonce = nonce.wrapping_add(1);
+        }
+        attempts.fetch_add(ATTEMPTS_PER_BATCH, Ordering::Relaxed);
+        if terminated.load(Ordering::Relaxed) {
+            return None;
+        }
+    }
+}
+
+// Checks work as it appears in a block (the nonce read big-endian)
+// against a root, the same way the node will before accepting it.
+pub fn validate_work(root: &[u8; 32], work: u64, threshold: u64) -> bool {
+    WorkHasher::new(root).difficulty(work) >= threshold
+}
+
+const IV: [u64; 8] = [
+    0x6a09e667f3bcc908,
+    0xbb67ae8584caa73b,
+    0x3c6ef372fe94f82b,
+    0xa54ff53a5f1d36f1,
+    0x510e527fade682d1,
+    0x9b05688c2b3e6c1f,
+    0x1f83d9abfb41bd6b,
+    0x5be0cd19137e2179,
+];
+
+const SIGMA: [[usize; 16]; 10] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    [14, 10, 4, 8, 9, 15, 13, 6, 1, 12, 0, 2, 11, 7, 5, 3],
+    [11, 8, 12, 0, 5, 2, 15, 13, 10, 14, 3, 6, 7, 1, 9, 4],
+    [7, 9, 3, 1, 13, 12, 11, 14, 2, 6, 5, 10, 4, 0, 15, 8],
+    [9, 0, 5, 7, 2, 4, 10, 15, 14, 1, 11, 12, 6, 8, 3, 13],
+    [2, 12, 6, 10, 0, 11, 8, 3, 4, 13, 7, 5, 15, 14, 1, 9],
+    [12, 5, 1, 15, 14, 13, 4, 10, 0, 7, 6, 3, 9, 2, 8, 11],
+    [13, 11, 7, 14, 12, 1, 3, 9, 5, 0, 15, 4, 8, 6, 2, 10],
+    [6, 15, 14, 9, 11, 3, 0, 8, 12, 2, 13, 7, 1, 4, 10, 5],
+    [10, 2, 8, 4, 7, 6, 1, 5, 15, 11, 9, 14, 3, 12, 13, 0],
+];
+
+// BLAKE2b cut down to what work needs: one 40 byte block (nonce then
+// root) hashed to an 8 byte digest. The parameter block and root are
+// set up once, so each attempt is a single compression on the stack.
+struct WorkHasher {
+    state: [u64; 8],
+    message: [u64; 16],
+}
+
+impl WorkHasher {
+    fn new(root: &[u8; 32]) -> WorkHasher {
+        let mut state = IV;
+        // No key, fanout and depth of 1, 8 byte digest.
+        state[0] ^= 0x0101_0000 ^ 8;
+        let mut message = [0u64; 16];
+        for (i, word) in root.chunks_exact(8).enumerate() {
+            message[i + 1] = u64::from_le_bytes(word.try_into().unwrap());
+        }
+        WorkHasher { state, message }
+    }
+
+    fn difficulty(&mut self, nonce: u64) -> u64 {
+        self.message[0] = nonce;
+        let m = &self.message;
+        let mut v = [0u64; 16];
+        v[..8].copy_from_slice(&self.state);
+        v[8..].copy_from_slice(&IV);
+        // 40 bytes hashed, and this is the final block.
+        v[12] ^= 40;
+        v[14] = !v[14];
+        for round in 0..12 {
+            let s = &SIGMA[round % 10];
+            mix(&mut v, 0, 4, 8, 12, m[s[0]], m[s[1]]);
+            mix(&mut v, 1, 5, 9, 13, m[s[2]], m[s[3]]);
+            mix(&mut v, 2, 6, 10, 14, m[s[4]], m[s[5]]);
+            mix(&mut v, 3, 7, 11, 15, m[s[6]], m[s[7]]);
+            mix(&mut v, 0, 5, 10, 15, m[s[8]], m[s[9]]);
+            mix(&mut v, 1, 6, 11, 12, m[s[10]], m[s[11]]);
+            mix(&mut v, 2, 7, 8, 13, m[s[12]], m[s[13]]);
+            mix(&mut v, 3, 4, 9, 14, m[s[14]], m[s[15]]);
+        }
+        // The digest is the first 8 bytes of the state, read the same
+        // little-endian way the node reads difficulty.
+        self.state[0] ^ v[0] ^ v[8]
+    }
+}
+
+#[inline(always)]
+fn mix(v: &mut [u64; 16], a: usize, b: usize, c: usize, d: usize, x: u64, y: u64) {
+    v[a] = v[a].wrapping_add(v[b]).wrapping_add(x);
+    v[d] = (v[d] ^ v[a]).rotate_right(32);
+    v[c] = v[c].wrapping_add(v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(24);
+    v[a] = v[a].wrapping_add(v[b]).wrapping_add(y);
+    v[d] = (v[d] ^ v[a]).rotate_right(16);
+    v[c] = v[c].wrapping_add(v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(63);
+}
@@ -0,0 +1,255 @@
+use super::keys::{get_private_key, SecretKey};
+use super::mnemonic::wordlist_position;
+use super::wordlist::WORD_LIST;
+use crate::error::{Error, Result};
+use bitreader::BitReader;
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+
+pub const MAX_SHARES: u8 = 16;
+// kind, threshold, index, the two byte id of the split and the four
+// byte fingerprint of account 0.
+const HEADER_LENGTH: usize = 9;
+const MIN_CHECKSUM_BITS: usize = 8;
+
+// What a split secret is, so that it can be restored as the right
+// kind of wallet.
+pub struct ShareKind;
+impl ShareKind {
+    pub const SEED: u8 = 0;
+    pub const PRIVATE_KEY: u8 = 1;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Share {
+    pub kind: u8,
+    pub threshold: u8,
+    // The x coordinate of the share, from 1 to the number of shares.
+    pub index: u8,
+    // Random per split, so that shares of different splits aren't mixed.
+    pub id: [u8; 2],
+    // The start of the SHA-256 of account 0's public key, to check the
+    // rebuilt secret against.
+    pub fingerprint: [u8; 4],
+    pub data: Vec<u8>,
+}
+
+impl Share {
+    // Encodes the share like a BIP39 mnemonic: 11 bits per word, with as
+    // many bits of SHA-256 checksum as it takes to fill the last word.
+    pub fn to_words(&self) -> String {
+        let mut bytes = vec![self.kind, self.threshold, self.index];
+        bytes.extend(self.id);
+        bytes.extend(self.fingerprint);
+        bytes.extend(&self.data);
+        let checksum_bits = checksum_bits(bytes.len());
+        let word_count = (bytes.len() * 8 + checksum_bits) / 11;
+        let check = Sha256::digest(&bytes);
+        bytes.extend(&check[..checksum_bits.div_ceil(8)]);
+
+        let mut reader = BitReader::new(&bytes);
+        let mut words: Vec<&str> = vec![];
+        for _ in 0..word_count {
+            words.push(WORD_LIST[reader.read_u16(11).unwrap() as usize]);
+        }
+        words.join(" ")
+    }
+
+    pub fn from_words(share: &str) -> Result<Share> {
+        let mut bits = vec![];
+        for word in share.split_whitespace() {
+            let word = word.to_lowercase();
+            if !WORD_LIST.contains(&word.as_str()) {
+                return Err(Error::Decode(format!(
+                    "\"{}\" is not in the word list",
+                    word
+                )));
+            }
+            let n = wordlist_position(&word);
+            for j in 0..11 {
+                bits.push(n >> (10 - j) & 1 == 1);
+            }
+        }
+        // A length with a long checksum can give the same number of words
+        // as the next length up with a short one, so try each in turn.
+        let bytes = (HEADER_LENGTH + 1..=bits.len() / 8)
+            .rev()
+            .filter(|length| length * 8 + checksum_bits(*length) == bits.len())
+            .find_map(|length| checked_bytes(&bits, length))
+            .ok_or_else(|| {
+                Error::Decode(String::from(
+                    "the share's checksum doesn't match or it has the wrong number of words",
+                ))
+            })?;
+        Ok(Share {
+            kind: bytes[0],
+            threshold: bytes[1],
+            index: bytes[2],
+            id: [bytes[3], bytes[4]],
+            fingerprint: bytes[5..9].try_into().unwrap(),
+            data: bytes[HEADER_LENGTH..].to_vec(),
+        })
+    }
+}
+
+// Splits secret so that any threshold of the shares rebuild it, while
+// fewer reveal nothing about it.
+pub fn split_secret(secret: &[u8; 32], kind: u8, threshold: u8, shares: u8) -> Result<Vec<Share>> {
+    if threshold < 2 || threshold > shares || shares > MAX_SHARES {
+        return Err(Error::Crypto(format!(
+            "the threshold must be at least 2 and no more than the number of shares, which can be at most {}",
+            MAX_SHARES
+        )));
+    }
+    let fingerprint = fingerprint(secret, kind)?;
+    let mut csprng = rand::thread_rng();
+    let mut id = [0u8; 2];
+    csprng.fill_bytes(&mut id);
+    // One random polynomial per byte of the secret, of degree
+    // threshold - 1 and with the byte as its constant term.
+    let mut coefficients = vec![vec![0u8; threshold as usize]; secret.len()];
+    for (polynomial, byte) in coefficients.iter_mut().zip(secret) {
+        polynomial[0] = *byte;
+        csprng.fill_bytes(&mut polynomial[1..]);
+    }
+    Ok((1..=shares)
+        .map(|x| Share {
+            kind,
+            threshold,
+            index: x,
+            id,
+            fingerprint,
+            data: coefficients
+                .iter()
+                .map(|polynomial| {
+                    polynomial
+                        .iter()
+                        .rev()
+                        .fold(0, |acc, coefficient| gf_mul(acc, x) ^ coefficient)
+                })
+                .collect(),
+        })
+        .collect())
+}
+
+pub fn combine_shares(shares: &[Share]) -> Result<[u8; 32]> {
+    let first = shares
+        .first()
+        .ok_or_else(|| Error::Crypto(String::from("no shares were given")))?;
+    for share in shares {
+        if share.id != first.id
+            || share.fingerprint != first.fingerprint
+            || share.kind != first.kind
+            || share.threshold != first.threshold
+            || share.data.len() != first.data.len()
+        {
+            return Err(Error::Crypto(String::from(
+                "the shares are from different backups",
+            )));
+        }
+    }
+    let mut indexes: Vec<u8> = shares.iter().map(|share| share.index).collect();
+    indexes.sort_unstable();
+    indexes.dedup();
+    if indexes.contains(&0) || indexes.len() != shares.len() {
+        return Err(Error::Crypto(String::from(
+            "the same share was given more than once",
+        )));
+    }
+    if shares.len() < first.threshold as usize {
+        return Err(Error::Crypto(format!(
+            "{} shares are needed, but only {} were given",
+            first.threshold,
+            shares.len()
+        )));
+    }
+    // Lagrange interpolation at x = 0, byte by byte.
+    let shares = &shares[..first.threshold as usize];
+    let mut secret = vec![0u8; first.data.len()];
+    for (i, share) in shares.iter().enumerate() {
+        let mut basis = 1;
+        for (j, other) in shares.iter().enumerate() {
+            if i != j {
+                basis = gf_mul(basis, gf_div(other.index, other.index ^ share.index));
+            }
+        }
+        for (byte, y) in secret.iter_mut().zip(&share.data) {
+            *byte ^= gf_mul(basis, *y);
+        }
+    }
+    let secret: [u8; 32] = secret
+        .try_into()
+        .map_err(|_| Error::Decode(String::from("the shares aren't of a seed or private key")))?;
+    if fingerprint(&secret, first.kind)? != first.fingerprint {
+        return Err(Error::Crypto(String::from(
+            "the restored wallet's account 0 doesn't match the one the shares were made from",
+        )));
+    }
+    Ok(secret)
+}
+
+fn fingerprint(secret: &[u8; 32], kind: u8) -> Result<[u8; 4]> {
+    let private_key = match kind {
+        ShareKind::SEED => get_private_key(secret, 0),
+        ShareKind::PRIVATE_KEY => *secret,
+        _ => {
+            return Err(Error::Decode(String::from(
+                "the shares were made by a newer version of dagchat",
+            )))
+        }
+    };
+    let check = Sha256::digest(SecretKey(private_key).public_key().as_bytes());
+    Ok(check[..4].try_into().unwrap())
+}
+
+fn checked_bytes(bits: &[bool], length: usize) -> Option<Vec<u8>> {
+    let mut bytes = vec![0u8; length];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        for j in 0..8 {
+            if bits[i * 8 + j] {
+                *byte |= 1 << (7 - j);
+            }
+        }
+    }
+    let check = Sha256::digest(&bytes);
+    for (i, bit) in bits[length * 8..].iter().enumerate() {
+        if *bit != ((check[i / 8] & (1 << (7 - (i % 8)))) > 0) {
+            return None;
+        }
+    }
+    Some(bytes)
+}
+
+fn checksum_bits(length: usize) -> usize {
+    let mut bits = MIN_CHECKSUM_BITS;
+    while !(length * 8 + bits).is_multiple_of(11) {
+        bits += 1;
+    }
+    bits
+}
+
+// Multiplication in GF(2^8) with the AES polynomial.
+fn gf_mul(mut a: u8, mut b: u8) -> u8 {
+    let mut product = 0;
+    while b != 0 {
+        if b & 1 == 1 {
+            product ^= a;
+        }
+        let carry = a & 0x80;
+        a <<= 1;
+        if carry != 0 {
+            a ^= 0x1b;
+        }
+        b >>= 1;
+    }
+    product
+}
+
+fn gf_div(a: u8, b: u8) -> u8 {
+    // b^254 is the inverse of b, as every non-zero element has order 255.
+    let mut inverse = 1;
+    for _ in 0..254 {
+        inverse = gf_mul(inverse, b);
+    }
+    gf_mul(a, inverse)
+}
@@ -0,0 +1,55 @@
+use super::blocks::{Amount, Hash, StateBlock, Subtype};
+use super::keys::{PublicKey, SecretKey};
+use crate::coin::Coin;
+use crate::constants::{BANANO_MESSAGE_PREAMBLE, NANO_MESSAGE_PREAMBLE};
+use crate::error::{Error, Result};
+use blake2::digest::{Update, VariableOutput};
+use blake2::Blake2bVar;
+
+pub fn sign_message(private_key_bytes: &[u8; 32], message: &str, coin: &Coin) -> Result<String> {
+    let private_key = SecretKey(*private_key_bytes);
+    let mut block = message_block(private_key.public_key(), message, coin);
+    block.sign(&private_key);
+    Ok(hex::encode(block.signature.unwrap()))
+}
+
+// Checks a signature made by sign_message. Err is for an address or
+// signature that can't be decoded, Ok(false) for one that doesn't match.
+pub fn verify_message(address: &str, message: &str, signature: &str, coin: &Coin) -> Result<bool> {
+    let public_key = PublicKey::from_address(address)?;
+    let signature: [u8; 64] = hex::decode(signature)
+        .ok()
+        .and_then(|signature| signature.try_into().ok())
+        .ok_or_else(|| {
+            Error::Decode(String::from(
+                "the signature should be 128 hexadecimal characters",
+            ))
+        })?;
+    let mut block = message_block(public_key, message, coin);
+    block.signature = Some(signature);
+    Ok(block.verify_signature())
+}
+
+// The dummy change block that is signed in place of the message.
+fn message_block(account: PublicKey, message: &str, coin: &Coin) -> StateBlock {
+    let mut hasher = Blake2bVar::new(32).unwrap();
+    let mut message_encoded_rep_buf = [0u8; 32];
+    if coin.name == "nano" {
+        hasher.update(NANO_MESSAGE_PREAMBLE);
+    } else if coin.name == "banano" {
+        hasher.update(BANANO_MESSAGE_PREAMBLE);
+    }
+    hasher.update(message.as_bytes());
+    hasher
+        .finalize_variable(&mut message_encoded_rep_buf)
+        .unwrap();
+    StateBlock::new(
+        Subtype::Change,
+        account,
+        Hash([0; 32]),
+        //hashed message goes into rep field
+        PublicKey(message_encoded_rep_buf),
+        Amount(0),
+        Hash([0; 32]),
+    )
+}
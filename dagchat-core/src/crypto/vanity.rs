@@ -0,0 +1,125 @@
+use super::address::{get_address, ADDR_ENCODING};
+use super::keys::{get_private_key, SecretKey};
+use super::pow::{run_threads, Attempts, Terminated, WorkControl};
+use crate::error::{Error, Result};
+use rand::RngCore;
+use std::sync::atomic::Ordering;
+
+// Public keys are derived one at a time, so batches are far smaller
+// than for work.
+const ATTEMPTS_PER_BATCH: u64 = 64;
+// Characters of an address after its prefix: 52 of public key and 8 of
+// checksum. The first is always 1 or 3, as it only holds one bit of
+// the key.
+const ADDRESS_LENGTH: usize = 60;
+
+// What is generated for each attempt. A seed's address is that of its
+// account 0.
+pub struct VanityKind;
+impl VanityKind {
+    pub const SEED: usize = 0;
+    pub const PRIVATE_KEY: usize = 1;
+}
+
+// An address pattern: the characters that follow nano_1/ban_1 and the
+// characters the address ends with.
+#[derive(Debug, Clone, Default)]
+pub struct VanityPattern {
+    pub prefix: String,
+    pub suffix: String,
+}
+
+impl VanityPattern {
+    pub fn new(prefix: &str, suffix: &str) -> Result<VanityPattern> {
+        let prefix = prefix.trim().to_lowercase();
+        let suffix = suffix.trim().to_lowercase();
+        if prefix.is_empty() && suffix.is_empty() {
+            return Err(Error::Decode(String::from(
+                "enter a prefix, a suffix or both",
+            )));
+        }
+        if prefix.len() + suffix.len() > ADDRESS_LENGTH - 1 {
+            return Err(Error::Decode(String::from(
+                "the pattern is longer than an address",
+            )));
+        }
+        let symbols = ADDR_ENCODING.specification().symbols;
+        if let Some(c) = prefix
+            .chars()
+            .chain(suffix.chars())
+            .find(|c| !symbols.contains(*c))
+        {
+            return Err(Error::Decode(format!(
+                "addresses can't contain '{}', only the characters {}",
+                c, symbols
+            )));
+        }
+        Ok(VanityPattern { prefix, suffix })
+    }
+
+    // Each character is one of 32, and the 1 before a prefix is one of
+    // two, so matching is this unlikely.
+    pub fn expected_attempts(&self) -> f64 {
+        let attempts = 32f64.powi((self.prefix.len() + self.suffix.len()) as i32);
+        if self.prefix.is_empty() {
+            attempts
+        } else {
+            attempts * 2.0
+        }
+    }
+
+    // Chance of having found a match after some number of attempts.
+    pub fn probability(&self, attempts: u64) -> f64 {
+        1.0 - (1.0 - 1.0 / self.expected_attempts()).powf(attempts as f64)
+    }
+
+    fn matches(&self, address: &str) -> bool {
+        let prefix_matches = self.prefix.is_empty()
+            || (address.starts_with('1') && address[1..].starts_with(&self.prefix));
+        prefix_matches && address.ends_with(&self.suffix)
+    }
+}
+
+// Searches on every core for a seed or private key whose address
+// matches the pattern. Progress is told the attempts made so far.
+pub fn find_vanity(
+    pattern: &VanityPattern,
+    kind: usize,
+    control: &WorkControl,
+    progress: &dyn Fn(u64),
+) -> Result<[u8; 32]> {
+    let pattern = pattern.clone();
+    let (secret, _, _) = run_threads(control, None, progress, move |_, terminator, counter| {
+        search_vanity(terminator, counter, &pattern, kind)
+    });
+    secret.ok_or(Error::Cancelled)
+}
+
+fn search_vanity(
+    terminated: Terminated,
+    attempts: Attempts,
+    pattern: &VanityPattern,
+    kind: usize,
+) -> Option<[u8; 32]> {
+    let mut csprng = rand::thread_rng();
+    let mut secret = [0u8; 32];
+    loop {
+        for _ in 0..ATTEMPTS_PER_BATCH {
+            csprng.fill_bytes(&mut secret);
+            let private_key = if kind == VanityKind::SEED {
+                get_private_key(&secret, 0)
+            } else {
+                secret
+            };
+            let public_key = SecretKey(private_key).public_key();
+            if pattern.matches(&get_address(public_key.as_bytes(), None)) {
+                terminated.store(true, Ordering::Relaxed);
+                return Some(secret);
+            }
+        }
+        attempts.fetch_add(ATTEMPTS_PER_BATCH, Ordering::Relaxed);
+        if terminated.load(Ordering::Relaxed) {
+            return None;
+        }
+    }
+}
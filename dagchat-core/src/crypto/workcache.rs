@@ -0,0 +1,99 @@
+use super::blocks::Hash;
+use super::keys::PublicKey;
+use super::pow::{generate_work, validate_work, WorkControl};
+use crate::error::{Error, Result};
+use crate::network::{Network, WorkType};
+use crate::rpc::accountinfo::{get_account_info, ACCOUNT_NOT_FOUND};
+use crate::rpc::workgenerate::get_server_work;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+// Work generated ahead of time for accounts' next blocks, keyed by the
+// root it was computed against. Clones share the same cache.
+#[derive(Debug, Clone, Default)]
+pub struct WorkCache {
+    work: Arc<Mutex<HashMap<Hash, u64>>>,
+}
+
+impl WorkCache {
+    pub fn get(&self, root: &Hash) -> Option<u64> {
+        self.work.lock().unwrap().get(root).copied()
+    }
+
+    pub fn contains(&self, root: &Hash) -> bool {
+        self.work.lock().unwrap().contains_key(root)
+    }
+
+    pub fn insert(&self, root: Hash, work: u64) {
+        self.work.lock().unwrap().insert(root, work);
+    }
+
+    pub fn remove(&self, root: &Hash) {
+        self.work.lock().unwrap().remove(root);
+    }
+
+    pub fn entries(&self) -> Vec<(Hash, u64)> {
+        let work = self.work.lock().unwrap();
+        work.iter().map(|(root, work)| (*root, *work)).collect()
+    }
+
+    pub fn extend(&self, entries: Vec<(Hash, u64)>) {
+        self.work.lock().unwrap().extend(entries);
+    }
+
+    // Makes sure there is cached work for the account's next block,
+    // whatever its subtype. Returns whether new work was generated.
+    pub fn precompute(
+        &self,
+        address: &str,
+        network: &Network,
+        control: &WorkControl,
+    ) -> Result<bool> {
+        if network.work_type == WorkType::BOOMPOW {
+            return Ok(false);
+        }
+        let root = match get_account_info(address, &network.node_url) {
+            Ok(account_info) => Hash::from_hex(&account_info.frontier)?,
+            // Unopened, so the next block is the open block.
+            Err(Error::Rpc(e)) if e == ACCOUNT_NOT_FOUND => {
+                Hash::from(PublicKey::from_address(address)?)
+            }
+            Err(e) => return Err(e),
+        };
+        if self.contains(&root) {
+            return Ok(false);
+        }
+        let threshold = next_block_threshold(network)?;
+        let mut work = None;
+        if network.work_type == WorkType::WORK_SERVER {
+            let server_work = get_server_work(
+                root.as_bytes(),
+                &format!("{:016x}", threshold),
+                &network.work_server_url,
+            )?;
+            work = u64::from_str_radix(&server_work, 16)
+                .ok()
+                .filter(|work| validate_work(root.as_bytes(), *work, threshold));
+        }
+        let work = match work {
+            Some(work) => work,
+            None => {
+                let cpu_work = generate_work(root.as_bytes(), threshold, control)?;
+                u64::from_str_radix(&cpu_work, 16)
+                    .map_err(|_| Error::Work(format!("invalid work: {}", cpu_work)))?
+            }
+        };
+        self.insert(root, work);
+        Ok(true)
+    }
+}
+
+// The next block could be a send or a receive, so precomputed work has
+// to meet the higher of the two thresholds.
+fn next_block_threshold(network: &Network) -> Result<u64> {
+    let send = u64::from_str_radix(&network.send_thresh, 16)
+        .map_err(|_| Error::Work(format!("invalid threshold: {}", network.send_thresh)))?;
+    let receive = u64::from_str_radix(&network.receive_thresh, 16)
+        .map_err(|_| Error::Work(format!("invalid threshold: {}", network.receive_thresh)))?;
+    Ok(send.max(receive))
+}
@@ -0,0 +1,45 @@
+use std::fmt;
+
+#[derive(Debug)]
+pub enum Error {
+    // The request never reached the node or work server, or it
+    // answered with a non-success HTTP status.
+    Network(String),
+    // The node answered with an {"error": ...} body.
+    Rpc(String),
+    // The response or some input could not be parsed.
+    Decode(String),
+    // Encryption, decryption or key handling failed.
+    Crypto(String),
+    // Proof of work could not be obtained.
+    Work(String),
+    // A block fetched from the node failed hash, signature or
+    // chain checks and can't be trusted.
+    Tampered(String),
+    // The user cancelled the operation.
+    Cancelled,
+    // Part of a chain of blocks wasn't accepted by the node.
+    Rejected(String),
+    // A local file couldn't be read or written.
+    Io(String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Network(e) => write!(f, "network error: {}", e),
+            Error::Rpc(e) => write!(f, "node error: {}", e),
+            Error::Decode(e) => write!(f, "decode error: {}", e),
+            Error::Crypto(e) => write!(f, "crypto error: {}", e),
+            Error::Work(e) => write!(f, "work error: {}", e),
+            Error::Tampered(e) => write!(f, "tampered block: {}", e),
+            Error::Cancelled => write!(f, "cancelled"),
+            Error::Rejected(e) => write!(f, "rejected: {}", e),
+            Error::Io(e) => write!(f, "io error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
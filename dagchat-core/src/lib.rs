@@ -0,0 +1,11 @@
+pub mod coin;
+pub mod constants;
+pub mod crypto;
+pub mod error;
+pub mod messages;
+pub mod network;
+pub mod offline;
+pub mod rpc;
+pub mod uri;
+
+pub use error::{Error, Result};
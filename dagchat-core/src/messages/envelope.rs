@@ -0,0 +1,297 @@
+use crate::error::{Error, Result};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::io::{Read, Write};
+
+// Starts every envelope from v2 on. 0xFF never appears in UTF-8, so a
+// dagchat that only reads v1 fails on it with a decode error rather
+// than showing the rest as text.
+pub const ENVELOPE_MARKER: u8 = 0xFF;
+pub const PROTOCOL_VERSION: u8 = 2;
+// Marker, version, flags, content type and a 4 byte big-endian length.
+pub const HEADER_LENGTH: usize = 8;
+// Public key, nonce and MAC that ECIES adds to the plaintext.
+pub const ECIES_OVERHEAD: usize = 60;
+// Compressed content is only ever a few blocks, so anything inflating
+// past this is malformed or malicious.
+const MAX_CONTENT_LENGTH: u64 = 1 << 20;
+const COMPRESSION_MASK: u8 = 0b11;
+const ZSTD_LEVEL: i32 = 19;
+// Every 32 bytes is a block and its proof of work, so attachments are
+// kept small.
+pub const MAX_ATTACHMENT_LENGTH: usize = 16 * 1024;
+
+// Held in the low bits of the flags byte.
+pub struct Compression;
+impl Compression {
+    pub const NONE: u8 = 0;
+    pub const DEFLATE: u8 = 1;
+    pub const ZSTD: u8 = 2;
+}
+
+pub struct ContentType;
+impl ContentType {
+    pub const TEXT: u8 = 0;
+    pub const ATTACHMENT: u8 = 1;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Envelope {
+    pub version: u8,
+    pub content_type: u8,
+    pub content: Vec<u8>,
+}
+
+// Wraps content in a v2 envelope, compressed with whichever method
+// makes it smallest. The envelope still needs padding with pad.
+pub fn seal(content_type: u8, content: &[u8]) -> Vec<u8> {
+    let mut compression = Compression::NONE;
+    let mut payload = content.to_vec();
+    for (method, compressed) in [
+        (Compression::DEFLATE, deflate(content)),
+        (
+            Compression::ZSTD,
+            zstd::encode_all(content, ZSTD_LEVEL).ok(),
+        ),
+    ] {
+        if let Some(compressed) = compressed {
+            if compressed.len() < payload.len() {
+                compression = method;
+                payload = compressed;
+            }
+        }
+    }
+    let mut plaintext = vec![ENVELOPE_MARKER, PROTOCOL_VERSION, compression, content_type];
+    plaintext.extend((payload.len() as u32).to_be_bytes());
+    plaintext.extend(payload);
+    plaintext
+}
+
+// Zero pads a sealed envelope so that, once encryption adds overhead
+// bytes, it fills a whole number of buckets of bucket_blocks blocks.
+// The header holds the real length, so readers ignore the padding.
+// Buckets of more than one block hide how long a message is from
+// anyone watching the chain, as every message in a bucket looks alike.
+// With random set it instead fills a random number of whole blocks, up
+// to the end of the bucket, so lengths don't cluster on the boundaries.
+pub fn pad(plaintext: &mut Vec<u8>, overhead: usize, bucket_blocks: usize, random: bool) {
+    let mut length = padded_length(plaintext.len() + overhead, bucket_blocks);
+    if random {
+        let least = padded_length(plaintext.len() + overhead, 1) / 32;
+        length = 32 * rand::thread_rng().gen_range(least, length / 32 + 1);
+    }
+    plaintext.resize(length - overhead, 0);
+}
+
+// The length encrypted bytes take once padded to whole buckets.
+pub fn padded_length(length: usize, bucket_blocks: usize) -> usize {
+    let bucket = 32 * bucket_blocks.max(1);
+    length.div_ceil(bucket) * bucket
+}
+
+// Reads a decrypted plaintext. Anything without the marker is a v1
+// message: UTF-8 text padded with spaces, which can't be told apart
+// from spaces the sender typed, so v1 is only ever read.
+pub fn open(plaintext: &[u8]) -> Result<Envelope> {
+    if plaintext.first() != Some(&ENVELOPE_MARKER) {
+        let length = plaintext.len() - plaintext.iter().rev().take_while(|b| **b == b' ').count();
+        return Ok(Envelope {
+            version: 1,
+            content_type: ContentType::TEXT,
+            content: plaintext[..length].to_vec(),
+        });
+    }
+    if plaintext.len() < HEADER_LENGTH {
+        return Err(Error::Decode(String::from(
+            "the message's header is incomplete",
+        )));
+    }
+    let version = plaintext[1];
+    if version != PROTOCOL_VERSION {
+        return Err(Error::Decode(format!(
+            "the message uses version {} of the dagchat protocol, which this version of dagchat can't read. Update dagchat to read it",
+            version
+        )));
+    }
+    let flags = plaintext[2];
+    // A flag this version doesn't know about would change how the rest
+    // is read.
+    if flags & !COMPRESSION_MASK != 0 {
+        return Err(Error::Decode(format!(
+            "the message uses flags {:#010b} that this version of dagchat can't read. Update dagchat to read it",
+            flags
+        )));
+    }
+    let compression = flags & COMPRESSION_MASK;
+    let content_type = plaintext[3];
+    let length = u32::from_be_bytes(plaintext[4..HEADER_LENGTH].try_into().unwrap()) as usize;
+    let payload = plaintext
+        .get(HEADER_LENGTH..HEADER_LENGTH + length)
+        .ok_or_else(|| {
+            Error::Decode(String::from("the message is shorter than its header says"))
+        })?;
+    let content = match compression {
+        Compression::NONE => Some(payload.to_vec()),
+        Compression::DEFLATE => {
+            let decoder = flate2::read::DeflateDecoder::new(payload);
+            read_limited(decoder)
+        }
+        Compression::ZSTD => zstd::Decoder::new(payload).ok().and_then(read_limited),
+        _ => {
+            return Err(Error::Decode(format!(
+                "unknown compression method {}",
+                compression
+            )))
+        }
+    }
+    .ok_or_else(|| Error::Decode(String::from("the message couldn't be decompressed")))?;
+    Ok(Envelope {
+        version,
+        content_type,
+        content,
+    })
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Attachment {
+    pub filename: String,
+    pub mime_type: String,
+    pub data: Vec<u8>,
+}
+
+// What a message carries: text, and optionally a file that the text
+// describes.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Content {
+    pub text: String,
+    pub attachment: Option<Attachment>,
+}
+
+impl Content {
+    pub fn text(text: String) -> Content {
+        Content {
+            text,
+            attachment: None,
+        }
+    }
+
+    // Attachment content is the filename and MIME type, each after a
+    // length byte, the text after a 2 byte big-endian length, then the
+    // file itself.
+    pub fn seal(&self) -> Vec<u8> {
+        let attachment = match &self.attachment {
+            Some(attachment) => attachment,
+            None => return seal(ContentType::TEXT, self.text.as_bytes()),
+        };
+        let mut content = vec![];
+        for field in [&attachment.filename, &attachment.mime_type] {
+            let field = truncate(field, u8::MAX as usize);
+            content.push(field.len() as u8);
+            content.extend(field.as_bytes());
+        }
+        let text = truncate(&self.text, u16::MAX as usize);
+        content.extend((text.len() as u16).to_be_bytes());
+        content.extend(text.as_bytes());
+        content.extend(&attachment.data);
+        seal(ContentType::ATTACHMENT, &content)
+    }
+
+    pub fn open(plaintext: &[u8]) -> Result<Content> {
+        let envelope = open(plaintext)?;
+        match envelope.content_type {
+            ContentType::TEXT => Ok(Content::text(utf8(envelope.content)?)),
+            ContentType::ATTACHMENT => {
+                let mut reader = envelope.content.as_slice();
+                let filename = utf8(take(&mut reader, 1)?)?;
+                let mime_type = utf8(take(&mut reader, 1)?)?;
+                let text = utf8(take(&mut reader, 2)?)?;
+                Ok(Content {
+                    text,
+                    attachment: Some(Attachment {
+                        filename,
+                        mime_type,
+                        data: reader.to_vec(),
+                    }),
+                })
+            }
+            content_type => Err(Error::Decode(format!(
+                "unknown content type {}. Update dagchat to read it",
+                content_type
+            ))),
+        }
+    }
+}
+
+// A MIME type from the file's extension, for the few kinds of file
+// small enough to send.
+pub fn guess_mime_type(filename: &str) -> &'static str {
+    let extension = filename
+        .rsplit_once('.')
+        .map(|(_, extension)| extension.to_lowercase())
+        .unwrap_or_default();
+    match extension.as_str() {
+        "txt" | "asc" | "sig" => "text/plain",
+        "json" => "application/json",
+        "pdf" => "application/pdf",
+        "pem" => "application/x-pem-file",
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "svg" => "image/svg+xml",
+        _ => "application/octet-stream",
+    }
+}
+
+// Reads a field preceded by its length in length_bytes big-endian bytes.
+fn take(reader: &mut &[u8], length_bytes: usize) -> Result<Vec<u8>> {
+    let incomplete = || Error::Decode(String::from("the attachment's header is incomplete"));
+    if reader.len() < length_bytes {
+        return Err(incomplete());
+    }
+    let (length, rest) = reader.split_at(length_bytes);
+    let length = length
+        .iter()
+        .fold(0usize, |acc, byte| acc << 8 | *byte as usize);
+    if rest.len() < length {
+        return Err(incomplete());
+    }
+    let (field, rest) = rest.split_at(length);
+    *reader = rest;
+    Ok(field.to_vec())
+}
+
+fn utf8(bytes: Vec<u8>) -> Result<String> {
+    String::from_utf8(bytes).map_err(|_| {
+        Error::Decode(String::from(
+            "Error decrypting message: format was not UTF-8.",
+        ))
+    })
+}
+
+// Cuts text to at most max bytes, on a character boundary.
+fn truncate(text: &str, max: usize) -> &str {
+    let mut end = text.len().min(max);
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    &text[..end]
+}
+
+fn deflate(content: &[u8]) -> Option<Vec<u8>> {
+    let mut encoder = flate2::write::DeflateEncoder::new(Vec::new(), flate2::Compression::best());
+    encoder.write_all(content).ok()?;
+    encoder.finish().ok()
+}
+
+fn read_limited<R: Read>(reader: R) -> Option<Vec<u8>> {
+    let mut content = vec![];
+    reader
+        .take(MAX_CONTENT_LENGTH + 1)
+        .read_to_end(&mut content)
+        .ok()?;
+    if content.len() as u64 > MAX_CONTENT_LENGTH {
+        return None;
+    }
+    Some(content)
+}
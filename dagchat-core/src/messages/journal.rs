@@ -0,0 +1,133 @@
+use crate::coin::Coin;
+use crate::crypto::blocks::{Hash, StateBlock, Subtype};
+use crate::crypto::keys::PublicKey;
+use crate::crypto::pow::WorkControl;
+use crate::error::{Error, Result};
+use crate::rpc::accountinfo::{get_account_info, ACCOUNT_NOT_FOUND};
+use crate::rpc::blockinfo::Block;
+use crate::rpc::changerep::change_rep;
+use crate::rpc::process::publish_chain;
+use serde::{Deserialize, Serialize};
+use std::cell::Cell;
+
+// A message send that has been built but may not have been fully
+// published, kept so that it can be finished or undone later.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Journal {
+    pub account: String,
+    pub recipient: String,
+    // Every block of the message in order, signed and with work.
+    pub blocks: Vec<(Subtype, Block)>,
+    // How many of the blocks are known to be on chain.
+    pub confirmed: usize,
+}
+
+impl Journal {
+    pub fn new(blocks: &[StateBlock], recipient: &str, coin: &Coin) -> Journal {
+        Journal {
+            account: blocks[0].account.to_address(&coin.prefix),
+            recipient: String::from(recipient),
+            blocks: blocks
+                .iter()
+                .map(|block| (block.subtype, block.to_block(&coin.prefix)))
+                .collect(),
+            confirmed: 0,
+        }
+    }
+
+    pub fn state_blocks(&self) -> Result<Vec<StateBlock>> {
+        self.blocks
+            .iter()
+            .map(|(subtype, block)| StateBlock::from_block(block, *subtype))
+            .collect()
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.confirmed == self.blocks.len()
+    }
+
+    // The representative the account had before the message was sent,
+    // which the last block of the message puts back.
+    pub fn original_rep(&self, coin: &Coin) -> Result<String> {
+        let blocks = self.state_blocks()?;
+        let restore = blocks
+            .last()
+            .ok_or_else(|| Error::Decode(String::from("journal has no blocks")))?;
+        Ok(restore.representative.to_address(&coin.prefix))
+    }
+
+    // The journal is written after each block is accepted, so dagchat
+    // may have stopped between the two. The account's frontier says
+    // how far the chain really got.
+    pub fn sync(&mut self, coin: &Coin) -> Result<()> {
+        let blocks = self.state_blocks()?;
+        let frontier = match get_account_info(&self.account, &coin.network.node_url) {
+            Ok(account_info) => Hash::from_hex(&account_info.frontier)?,
+            Err(Error::Rpc(e)) if e == ACCOUNT_NOT_FOUND => Hash([0u8; 32]),
+            Err(e) => return Err(e),
+        };
+        if frontier == blocks[0].previous {
+            self.confirmed = 0;
+            return Ok(());
+        }
+        match blocks.iter().position(|block| block.hash() == frontier) {
+            Some(idx) => {
+                self.confirmed = idx + 1;
+                Ok(())
+            }
+            None => Err(Error::Rejected(format!(
+                "{} has moved on since the message to {} was built, so it can't be resumed",
+                self.account, self.recipient
+            ))),
+        }
+    }
+
+    // Publishes the blocks that didn't make it on to the chain. The
+    // journal is passed to on_published whenever another is accepted.
+    pub fn resume(&mut self, coin: &Coin, on_published: &dyn Fn(&Journal)) -> Result<()> {
+        self.sync(coin)?;
+        let blocks = self.state_blocks()?;
+        let journal = self.clone();
+        let confirmed = Cell::new(self.confirmed);
+        let result = publish_chain(&blocks, self.confirmed, coin, &|n| {
+            confirmed.set(n);
+            on_published(&Journal {
+                confirmed: n,
+                ..journal.clone()
+            })
+        });
+        self.confirmed = confirmed.get();
+        result
+    }
+
+    // Abandons the rest of the message, only changing the account's
+    // representative back to what it was before the send. This works
+    // however far the account has moved on since.
+    pub fn restore(
+        &self,
+        private_key_bytes: &[u8; 32],
+        coin: &Coin,
+        control: &WorkControl,
+    ) -> Result<Option<String>> {
+        let original_rep = self.original_rep(coin)?;
+        let account_info = match get_account_info(&self.account, &coin.network.node_url) {
+            Ok(account_info) => account_info,
+            // Never opened, so there's nothing to restore.
+            Err(Error::Rpc(e)) if e == ACCOUNT_NOT_FOUND => return Ok(None),
+            Err(e) => return Err(e),
+        };
+        if PublicKey::from_address(&account_info.representative)?
+            == PublicKey::from_address(&original_rep)?
+        {
+            return Ok(None);
+        }
+        let hash = change_rep(
+            private_key_bytes,
+            account_info,
+            &original_rep,
+            coin,
+            control,
+        )?;
+        Ok(Some(hash))
+    }
+}
@@ -0,0 +1,8 @@
+pub mod envelope;
+pub mod journal;
+pub mod multicast;
+pub mod read;
+pub mod recover;
+pub mod representative;
+pub mod send;
+pub mod structs;
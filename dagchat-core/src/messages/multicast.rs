@@ -0,0 +1,102 @@
+use crate::crypto::keys::PublicKey;
+use crate::error::{Error, Result};
+use aes_gcm::aead::{generic_array::GenericArray, Aead, NewAead};
+use aes_gcm::{Aes256Gcm, Nonce};
+use rand::RngCore;
+
+// Starts the first block of a message sent to several recipients. A
+// single recipient's message starts with a random ephemeral public key
+// instead, which matching this is never going to happen by chance.
+pub const MULTICAST_MAGIC: &[u8; 8] = b"dagchatM";
+// The magic, the number of key slots, the body's length as 4 bytes
+// big-endian, then zeros: one whole block.
+const HEADER_LENGTH: usize = 32;
+// ECIES around a 32 byte content key.
+pub const SLOT_LENGTH: usize = 32 + 12 + 32 + 16;
+// The AES-GCM tag on the body.
+const TAG_LENGTH: usize = 16;
+// Each recipient adds a key slot and a send block.
+pub const MAX_RECIPIENTS: usize = 32;
+
+// Encrypts the plaintext once under a random content key, and the key
+// once for each recipient, so the chain holds a single copy of the
+// message however many recipients there are.
+pub fn seal_multicast(recipients: &[PublicKey], plaintext: &[u8]) -> Vec<u8> {
+    let mut csprng = rand::thread_rng();
+    let mut content_key = [0u8; 32];
+    csprng.fill_bytes(&mut content_key);
+    let aead = Aes256Gcm::new(GenericArray::from_slice(&content_key));
+    // The key is never used again, so a fixed nonce is safe and saves
+    // storing one on chain.
+    let body = aead
+        .encrypt(Nonce::from_slice(&[0u8; 12]), plaintext)
+        .unwrap();
+
+    let mut ciphertext = MULTICAST_MAGIC.to_vec();
+    ciphertext.push(recipients.len() as u8);
+    ciphertext.extend((body.len() as u32).to_be_bytes());
+    ciphertext.resize(HEADER_LENGTH, 0);
+    for recipient in recipients {
+        let public_key = ecies_ed25519::PublicKey::from_bytes(recipient.as_bytes()).unwrap();
+        let slot = ecies_ed25519::encrypt(&public_key, &content_key, &mut csprng).unwrap();
+        ciphertext.extend(slot);
+    }
+    ciphertext.extend(body);
+    let padding = (32 - ciphertext.len() % 32) % 32;
+    ciphertext.extend(vec![0u8; padding]);
+    ciphertext
+}
+
+pub fn is_multicast(ciphertext: &[u8]) -> bool {
+    ciphertext.starts_with(MULTICAST_MAGIC)
+}
+
+// Tries each key slot with the reader's key until one opens, then
+// decrypts the body with the content key inside it.
+pub fn open_multicast(
+    private_key: &ecies_ed25519::SecretKey,
+    ciphertext: &[u8],
+) -> Result<Vec<u8>> {
+    if ciphertext.len() < HEADER_LENGTH {
+        return Err(Error::Decode(String::from(
+            "the message's recipient header is incomplete",
+        )));
+    }
+    let slots = ciphertext[8] as usize;
+    let body_length = u32::from_be_bytes(ciphertext[9..13].try_into().unwrap()) as usize;
+    let body_start = HEADER_LENGTH + slots * SLOT_LENGTH;
+    let body = ciphertext
+        .get(body_start..body_start + body_length)
+        .filter(|body| body.len() >= TAG_LENGTH)
+        .ok_or_else(|| {
+            Error::Decode(String::from(
+                "the message is shorter than its recipient header says",
+            ))
+        })?;
+    let content_key = ciphertext[HEADER_LENGTH..body_start]
+        .chunks(SLOT_LENGTH)
+        .find_map(|slot| ecies_ed25519::decrypt(private_key, slot).ok())
+        .ok_or_else(|| {
+            Error::Crypto(String::from(
+                "Error decrypting message: it wasn't sent to this account.",
+            ))
+        })?;
+    if content_key.len() != 32 {
+        return Err(Error::Decode(String::from(
+            "the message's content key is the wrong length",
+        )));
+    }
+    let aead = Aes256Gcm::new(GenericArray::from_slice(&content_key));
+    aead.decrypt(Nonce::from_slice(&[0u8; 12]), body)
+        .map_err(|_| {
+            Error::Crypto(String::from(
+                "Error decrypting message: its body doesn't match its key.",
+            ))
+        })
+}
+
+// The ciphertext length of a message to the given number of recipients,
+// before padding to whole blocks.
+pub fn multicast_length(recipients: usize, plaintext_length: usize) -> usize {
+    HEADER_LENGTH + recipients * SLOT_LENGTH + plaintext_length + TAG_LENGTH
+}
@@ -0,0 +1,100 @@
+use super::envelope::Content;
+use super::multicast::{is_multicast, open_multicast};
+use super::structs::Message;
+use crate::crypto::blocks::{Hash, StateBlock, Subtype};
+use crate::crypto::keys::to_public_key;
+use crate::error::{Error, Result};
+use crate::rpc::{blockinfo::Block, history::get_history};
+
+pub fn read_message(
+    private_key_bytes: &[u8; 32],
+    message: &Message,
+    node_url: &str,
+) -> Result<Content> {
+    let message_blocks = get_history(
+        &message.head.contents.account,
+        &message.root_hash,
+        message.blocks,
+        node_url,
+    )?;
+    verify_chain(&message_blocks, message)?;
+
+    let encrypted_bytes = extract_message(message_blocks);
+
+    let private_key = ecies_secret_key(private_key_bytes);
+    if is_multicast(&encrypted_bytes) {
+        return Content::open(&open_multicast(&private_key, &encrypted_bytes)?);
+    }
+    let decrypted = ecies_ed25519::decrypt(&private_key, &encrypted_bytes);
+    if decrypted.is_err() {
+        return Err(Error::Crypto(String::from(
+            "Error decrypting message: not sent using the dagchat protocol.",
+        )));
+    }
+    Content::open(&decrypted.unwrap())
+}
+
+// The key ECIES decrypts with for an account's private key.
+pub fn ecies_secret_key(private_key_bytes: &[u8; 32]) -> ecies_ed25519::SecretKey {
+    let dalek = ed25519_dalek::SecretKey::from_bytes(private_key_bytes).unwrap();
+    let expanded_bytes = ed25519_dalek::ExpandedSecretKey::from(&dalek);
+    ecies_ed25519::SecretKey::from_bytes(&expanded_bytes.to_bytes()[0..32]).unwrap()
+}
+
+// Checks that the message blocks are signed by the head's account and
+// form an unbroken previous chain from the root up to the head.
+pub fn verify_chain(blocks: &[Block], message: &Message) -> Result<()> {
+    let head = StateBlock::from_signed_block(&message.head.contents, Subtype::Send)?;
+    let root_hash = Hash::from_hex(&message.root_hash)?;
+    if Hash::from(head.representative) != root_hash {
+        return Err(Error::Tampered(format!(
+            "message head does not point at root {}",
+            root_hash.to_hex()
+        )));
+    }
+    let mut last_hash: Option<Hash> = None;
+    for block in blocks {
+        // account_history doesn't reliably report a block's own account,
+        // so signatures are checked against the head's account instead.
+        let block = Block {
+            account: message.head.contents.account.clone(),
+            ..block.clone()
+        };
+        let block = StateBlock::from_signed_block(&block, Subtype::Change)?;
+        let hash = block.hash();
+        let linked = match last_hash {
+            Some(last_hash) => block.previous == last_hash,
+            None => hash == root_hash,
+        };
+        if !linked {
+            return Err(Error::Tampered(format!(
+                "message block {} breaks the chain from root {}",
+                hash.to_hex(),
+                root_hash.to_hex()
+            )));
+        }
+        last_hash = Some(hash);
+    }
+    if last_hash != Some(head.previous) {
+        return Err(Error::Tampered(format!(
+            "message chain from root {} does not reach its head",
+            root_hash.to_hex()
+        )));
+    }
+    Ok(())
+}
+
+// The encrypted bytes held by the blocks from the root up. A message to
+// several recipients has a send to each after its data, and any before
+// this recipient's are among the blocks, so reading stops at the first.
+pub fn extract_message(blocks: Vec<Block>) -> Vec<u8> {
+    let mut encrypted_bytes = vec![];
+    for block in blocks {
+        if Hash::from_hex(&block.link).map_or(true, |link| !link.is_zero()) {
+            break;
+        }
+        let block_data = to_public_key(&block.representative);
+        encrypted_bytes.extend(&block_data);
+    }
+    encrypted_bytes
+}
@@ -0,0 +1,161 @@
+use super::envelope::Content;
+use super::read::read_message;
+use crate::coin::Coin;
+use crate::crypto::blocks::Hash;
+use crate::crypto::keys::{PublicKey, SecretKey};
+use crate::crypto::pow::WorkControl;
+use crate::error::{Error, Result};
+use crate::rpc::accountinfo::{get_account_info, ACCOUNT_NOT_FOUND};
+use crate::rpc::blockinfo::{get_blocks_info, BlockResponse};
+use crate::rpc::history::get_history_page;
+use crate::rpc::incoming::{head_message, root_hash, verified_head};
+use std::collections::{HashMap, HashSet};
+
+const HISTORY_PAGE: u64 = 500;
+const BLOCKS_INFO_BATCH: usize = 500;
+
+// A message found on chain, from a send to or received by the account.
+#[derive(Debug, Clone)]
+pub struct RecoveredMessage {
+    pub outgoing: bool,
+    // The other side of the message.
+    pub address: String,
+    // The hash of the send carrying the message.
+    pub hash: String,
+    pub amount: u128,
+    pub timestamp: u64,
+    pub content: Content,
+}
+
+// A send or receive in the account's history that may carry a message.
+struct Candidate {
+    outgoing: bool,
+    hash: String,
+    amount: u128,
+    timestamp: u64,
+}
+
+// Rescans the account's whole chain for messages it received, and for
+// ones it sent with a readable copy. Sends whose hash is in known are
+// skipped. Messages that don't decrypt, such as sends without a copy,
+// are left out.
+// progress is ticked up to a total of 1000 over the course of the scan.
+pub fn recover_messages(
+    private_key_bytes: &[u8; 32],
+    coin: &Coin,
+    known: &HashSet<String>,
+    progress: &dyn Fn(usize),
+    control: &WorkControl,
+) -> Result<Vec<RecoveredMessage>> {
+    let node_url = &coin.network.node_url;
+    let account = SecretKey(*private_key_bytes).public_key();
+    let address = account.to_address(&coin.prefix);
+    let block_count: u64 = match get_account_info(&address, node_url) {
+        Ok(account_info) => account_info.block_count.parse().unwrap_or(1),
+        // The account hasn't been opened, so it has no history.
+        Err(Error::Rpc(e)) if e == ACCOUNT_NOT_FOUND => return Ok(vec![]),
+        Err(e) => return Err(e),
+    };
+
+    let mut candidates = vec![];
+    let mut head = None;
+    loop {
+        if control.is_cancelled() {
+            return Err(Error::Cancelled);
+        }
+        let (blocks, next) = get_history_page(&address, head, HISTORY_PAGE, node_url)?;
+        let page_size = blocks.len() as u64;
+        for block in blocks {
+            let (outgoing, hash) = match block.subtype.as_str() {
+                "send" => (true, block.hash),
+                // A receive links to the send it received.
+                "receive" | "open" => (false, block.contents.link),
+                _ => continue,
+            };
+            let hash = hash.to_uppercase();
+            if known.contains(&hash) {
+                continue;
+            }
+            candidates.push(Candidate {
+                outgoing,
+                hash,
+                amount: block.amount.parse().unwrap_or(0),
+                timestamp: block.local_timestamp.parse().unwrap_or(0),
+            });
+        }
+        progress((300 * page_size / block_count.max(1)) as usize);
+        match next {
+            Some(next) if page_size > 0 => head = Some(next),
+            _ => break,
+        }
+    }
+
+    let hashes = candidates.iter().map(|c| c.hash.clone()).collect();
+    let mut head_blocks = blocks_info(hashes, node_url)?;
+    let mut heads = vec![];
+    let mut root_hashes = vec![];
+    for candidate in candidates {
+        // A send to the account itself is in its history twice, and is
+        // only looked at the first time.
+        let head_block = match head_blocks.remove(&candidate.hash) {
+            Some(head_block) => head_block,
+            None => continue,
+        };
+        let head = verified_head(&candidate.hash, &head_block)?;
+        let counterpart = if candidate.outgoing {
+            if head.account != account {
+                return Err(Error::Tampered(format!(
+                    "block {} is not a send from {}",
+                    candidate.hash, address
+                )));
+            }
+            PublicKey(head.link.0)
+        } else {
+            if head.link != Hash::from(account) {
+                return Err(Error::Tampered(format!(
+                    "block {} is not a send to {}",
+                    candidate.hash, address
+                )));
+            }
+            head.account
+        };
+        root_hashes.push(root_hash(&head_block)?);
+        heads.push((candidate, head, head_block, counterpart));
+    }
+    let root_blocks = blocks_info(root_hashes, node_url)?;
+    progress(100);
+
+    let mut recovered = vec![];
+    let x = 600 / heads.len().max(1);
+    for (candidate, head, head_block, counterpart) in heads {
+        if control.is_cancelled() {
+            return Err(Error::Cancelled);
+        }
+        progress(x);
+        let message = match head_message(&candidate.hash, &head, head_block, &root_blocks)? {
+            Some(message) => message,
+            None => continue,
+        };
+        match read_message(private_key_bytes, &message, node_url) {
+            Ok(content) => recovered.push(RecoveredMessage {
+                outgoing: candidate.outgoing,
+                address: counterpart.to_address(&coin.prefix),
+                hash: candidate.hash,
+                amount: candidate.amount,
+                timestamp: candidate.timestamp,
+                content,
+            }),
+            Err(Error::Crypto(_)) | Err(Error::Decode(_)) => continue,
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(recovered)
+}
+
+fn blocks_info(hashes: Vec<String>, node_url: &str) -> Result<HashMap<String, BlockResponse>> {
+    let mut blocks = HashMap::new();
+    for batch in hashes.chunks(BLOCKS_INFO_BATCH) {
+        blocks.extend(get_blocks_info(batch.to_vec(), node_url)?.blocks.data);
+    }
+    Ok(blocks)
+}
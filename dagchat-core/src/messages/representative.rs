@@ -0,0 +1,74 @@
+use crate::crypto::blocks::Hash;
+use crate::crypto::keys::PublicKey;
+use crate::error::{Error, Result};
+use crate::network::Network;
+use crate::rpc::accountinfo::{get_account_info, ACCOUNT_NOT_FOUND};
+use crate::rpc::history::get_recent_history;
+use std::collections::{HashMap, HashSet};
+
+// How far back through an account's history to look for the
+// representative it had before a message send went wrong.
+const HISTORY_DEPTH: u64 = 100;
+
+// An account whose representative looks like part of a message rather
+// than a real representative.
+#[derive(Debug, Clone)]
+pub struct GarbageRep {
+    pub representative: String,
+    // The most recent representative on the account's chain that looks
+    // legitimate, if there is one within reach.
+    pub last_legit: Option<String>,
+}
+
+// Message blocks set the representative to ciphertext or to a block
+// hash, neither of which will be an opened account, and the latter will
+// also be the hash of a block on the account's own chain.
+pub fn check_representative(
+    address: &str,
+    representative: &str,
+    network: &Network,
+) -> Result<Option<GarbageRep>> {
+    if representative == network.default_rep {
+        return Ok(None);
+    }
+    let history = get_recent_history(address, HISTORY_DEPTH, &network.node_url)?;
+    let hashes: HashSet<Hash> = history
+        .iter()
+        .filter_map(|block| Hash::from_hex(&block.hash).ok())
+        .collect();
+    let mut checked: HashMap<PublicKey, bool> = HashMap::new();
+    let mut is_legit = |rep: &str| -> Result<bool> {
+        let key = PublicKey::from_address(rep)?;
+        if hashes.contains(&Hash::from(key)) {
+            return Ok(false);
+        }
+        if let Some(legit) = checked.get(&key) {
+            return Ok(*legit);
+        }
+        let legit = match get_account_info(rep, &network.node_url) {
+            Ok(_) => true,
+            Err(Error::Rpc(e)) if e == ACCOUNT_NOT_FOUND => false,
+            Err(e) => return Err(e),
+        };
+        checked.insert(key, legit);
+        Ok(legit)
+    };
+    if is_legit(representative)? {
+        return Ok(None);
+    }
+    let mut last_legit = None;
+    for block in &history {
+        let rep = &block.contents.representative;
+        if rep.is_empty() {
+            continue;
+        }
+        if is_legit(rep)? {
+            last_legit = Some(rep.clone());
+            break;
+        }
+    }
+    Ok(Some(GarbageRep {
+        representative: String::from(representative),
+        last_legit,
+    }))
+}
@@ -0,0 +1,215 @@
+use super::envelope::{pad, padded_length, Content, ECIES_OVERHEAD};
+use super::multicast::{multicast_length, seal_multicast, MAX_RECIPIENTS};
+use crate::coin::Coin;
+use crate::crypto::{
+    blocks::*,
+    keys::{PublicKey, SecretKey},
+    pow::WorkControl,
+};
+use crate::error::{Error, Result};
+use crate::rpc::{accountinfo::*, process::publish_chain};
+
+// How a message is encrypted and padded, beyond who it goes to.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MessageOptions {
+    // Gives the sender a key too, so they can read the message back
+    // from the chain.
+    pub sender_copy: bool,
+    // Pads the message to a multiple of this many blocks. 0 or 1 only
+    // pads to whole blocks.
+    pub bucket_blocks: usize,
+    // Pads to a random number of blocks up to the end of the bucket
+    // rather than always filling it.
+    pub random_padding: bool,
+}
+
+// progress is ticked up to a total of 900 over the course of the send:
+// 700 while building the chain and 200 while publishing it. Returns the
+// hash of the send to each recipient, in order.
+#[allow(clippy::too_many_arguments)]
+pub fn send_message(
+    private_key_bytes: &[u8; 32],
+    recipients: &[String],
+    raw: u128,
+    content: &Content,
+    options: &MessageOptions,
+    coin: &Coin,
+    progress: &dyn Fn(usize),
+    control: &WorkControl,
+) -> Result<Vec<String>> {
+    let blocks = build_message(
+        private_key_bytes,
+        recipients,
+        raw,
+        content,
+        options,
+        coin,
+        progress,
+        control,
+    )?;
+    let x = 200usize / blocks.len();
+    publish_chain(&blocks, 0, coin, &|_| progress(x))?;
+    Ok(send_hashes(&blocks))
+}
+
+// Builds every block of a message, signed and with work, without
+// publishing anything: the encrypted data blocks, a 1 raw send to each
+// recipient and the block restoring the representative. Cancelling
+// through the control therefore leaves the account untouched.
+// progress is ticked up to a total of 700 over the course of the build.
+#[allow(clippy::too_many_arguments)]
+pub fn build_message(
+    private_key_bytes: &[u8; 32],
+    recipients: &[String],
+    raw: u128,
+    content: &Content,
+    options: &MessageOptions,
+    coin: &Coin,
+    progress: &dyn Fn(usize),
+    control: &WorkControl,
+) -> Result<Vec<StateBlock>> {
+    let private_key = SecretKey(*private_key_bytes);
+    let sender_address = private_key.public_key().to_address(&coin.prefix);
+    let account_info = get_account_info(&sender_address, &coin.network.node_url)?;
+
+    progress(100);
+    let blocks = message_blocks(
+        private_key.public_key(),
+        &account_info,
+        recipients,
+        raw,
+        content,
+        options,
+    )?;
+    let x = 600usize / blocks.len();
+    let mut signed = vec![];
+    for block in blocks {
+        // Every hash is known locally, so the next block's work can be
+        // generated without waiting for this one to be published.
+        signed.push(get_signed_block(block, &private_key, coin, control)?);
+        progress(x);
+    }
+    Ok(signed)
+}
+
+// The unsigned blocks of a message sent from the account described by
+// account_info. Only the recipients' keys are needed to encrypt, so this
+// also works for accounts whose private key is kept elsewhere.
+// A single recipient gets the message encrypted straight to their key.
+// Several share one copy of it, with the key to it wrapped for each.
+pub fn message_blocks(
+    sender: PublicKey,
+    account_info: &AccountInfoResponse,
+    recipients: &[String],
+    raw: u128,
+    content: &Content,
+    options: &MessageOptions,
+) -> Result<Vec<StateBlock>> {
+    if recipients.is_empty() || recipients.len() > MAX_RECIPIENTS {
+        return Err(Error::Decode(format!(
+            "a message goes to between 1 and {} recipients",
+            MAX_RECIPIENTS
+        )));
+    }
+    let recipients = recipients
+        .iter()
+        .map(|address| PublicKey::from_address(address))
+        .collect::<Result<Vec<PublicKey>>>()?;
+    let mut readers = recipients.clone();
+    if options.sender_copy {
+        readers.push(sender);
+    }
+    let mut plaintext = content.seal();
+    pad(
+        &mut plaintext,
+        encryption_overhead(readers.len()),
+        options.bucket_blocks,
+        options.random_padding,
+    );
+    let encrypted_bytes = if readers.len() == 1 {
+        let public_key = ecies_ed25519::PublicKey::from_bytes(readers[0].as_bytes()).unwrap();
+        let mut csprng = rand::thread_rng();
+        ecies_ed25519::encrypt(&public_key, &plaintext, &mut csprng).unwrap()
+    } else {
+        seal_multicast(&readers, &plaintext)
+    };
+
+    // Set up the previous block hash and balance to start building blocks
+    // Also note the representative from before sending, in order to change back afterwards
+    let mut last_block_hash = Hash::from_hex(&account_info.frontier)?;
+    let mut balance = get_balance(account_info);
+    let representative = PublicKey::from_address(&account_info.representative)?;
+
+    let mut blocks = vec![];
+    for chunk in encrypted_bytes.chunks(32) {
+        let mut block_data = [0u8; 32];
+        block_data[..chunk.len()].copy_from_slice(chunk);
+        let block = StateBlock::new(
+            Subtype::Change,
+            sender,
+            last_block_hash,
+            PublicKey(block_data),
+            Amount(balance),
+            Hash([0u8; 32]),
+        );
+        last_block_hash = block.hash();
+        blocks.push(block);
+    }
+    let first_block_hash = blocks[0].hash();
+    for recipient in recipients {
+        // A send of 1 raw to each recipient
+        // Rep is the hash of the first block in the message
+        balance -= raw;
+        let block = StateBlock::new(
+            Subtype::Send,
+            sender,
+            last_block_hash,
+            PublicKey(first_block_hash.0),
+            Amount(balance),
+            Hash::from(recipient),
+        );
+        last_block_hash = block.hash();
+        blocks.push(block);
+    }
+    // Change representative to what it was at the start
+    blocks.push(StateBlock::new(
+        Subtype::Change,
+        sender,
+        last_block_hash,
+        representative,
+        Amount(balance),
+        Hash([0u8; 32]),
+    ));
+    Ok(blocks)
+}
+
+// The hashes of the sends in a message's blocks, one for each recipient.
+pub fn send_hashes(blocks: &[StateBlock]) -> Vec<String> {
+    blocks
+        .iter()
+        .filter(|block| block.subtype == Subtype::Send)
+        .map(|block| block.hash().to_hex())
+        .collect()
+}
+
+// Every block a message takes, each needing its own proof of work: the
+// encrypted data, a send to each recipient and the block restoring the
+// representative. With random padding this is the most it can take.
+pub fn message_block_count(
+    content: &Content,
+    recipients: usize,
+    options: &MessageOptions,
+) -> usize {
+    let readers = recipients + options.sender_copy as usize;
+    let encrypted_length = content.seal().len() + encryption_overhead(readers);
+    padded_length(encrypted_length, options.bucket_blocks) / 32 + recipients + 1
+}
+
+// What encrypting for this many readers adds to the plaintext.
+fn encryption_overhead(readers: usize) -> usize {
+    if readers > 1 {
+        multicast_length(readers, 0)
+    } else {
+        ECIES_OVERHEAD
+    }
+}
@@ -0,0 +1,13 @@
+use super::envelope::Attachment;
+use crate::rpc::blockinfo::BlockResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Message {
+    pub head: BlockResponse,
+    pub root_hash: String,
+    pub blocks: u64,
+    pub plaintext: String,
+    #[serde(default)]
+    pub attachment: Option<Attachment>,
+}
@@ -0,0 +1,401 @@
+use crate::coin::Coin;
+use crate::crypto::blocks::{attach_work, get_signed_block, Amount, Hash, StateBlock, Subtype};
+use crate::crypto::conversions::raw_to_whole;
+use crate::crypto::keys::{PublicKey, SecretKey};
+use crate::crypto::pow::WorkControl;
+use crate::error::{Error, Result};
+use crate::messages::envelope::Content;
+use crate::messages::send::{message_blocks, MessageOptions};
+use crate::network::WorkType;
+use crate::rpc::accountinfo::{
+    get_account_info, get_balance, AccountInfoResponse, ACCOUNT_NOT_FOUND,
+};
+use crate::rpc::blockinfo::Block;
+use crate::rpc::incoming::find_incoming;
+use crate::rpc::process::publish_chain;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::fs;
+
+// Bumped whenever the layout changes in a way older versions can't read.
+pub const TRANSACTION_VERSION: u32 = 1;
+
+// Blocks built by a watch-only instance, signed by an offline one and
+// then published by the watch-only one again. The same file is passed
+// back and forth, with signatures (and optionally work) filled in on
+// the offline instance.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Transaction {
+    pub version: u32,
+    pub coin: String,
+    pub account: String,
+    // What the blocks do, shown before they are signed or published.
+    pub description: String,
+    // One or more blocks forming a chain on the account, in order.
+    pub blocks: Vec<(Subtype, Block)>,
+}
+
+impl Transaction {
+    fn new(blocks: &[StateBlock], description: String, coin: &Coin) -> Transaction {
+        Transaction {
+            version: TRANSACTION_VERSION,
+            coin: coin.name.clone(),
+            account: blocks[0].account.to_address(&coin.prefix),
+            description,
+            blocks: blocks
+                .iter()
+                .map(|block| (block.subtype, block.to_block(&coin.prefix)))
+                .collect(),
+        }
+    }
+
+    pub fn send(
+        address: &str,
+        target_address: &str,
+        raw: u128,
+        coin: &Coin,
+    ) -> Result<Transaction> {
+        let account = PublicKey::from_address(address)?;
+        let account_info = get_account_info(address, &coin.network.node_url)?;
+        let balance = checked_balance(&account_info, raw, address)?;
+        let block = StateBlock::new(
+            Subtype::Send,
+            account,
+            Hash::from_hex(&account_info.frontier)?,
+            PublicKey::from_address(&account_info.representative)?,
+            Amount(balance),
+            Hash::from(PublicKey::from_address(target_address)?),
+        );
+        let description = format!("Send {} to {}", format_amount(raw, coin), target_address);
+        Ok(Transaction::new(&[block], description, coin))
+    }
+
+    pub fn message(
+        address: &str,
+        target_address: &str,
+        raw: u128,
+        message: String,
+        coin: &Coin,
+    ) -> Result<Transaction> {
+        let account = PublicKey::from_address(address)?;
+        let account_info = get_account_info(address, &coin.network.node_url)?;
+        checked_balance(&account_info, raw, address)?;
+        let blocks = message_blocks(
+            account,
+            &account_info,
+            &[String::from(target_address)],
+            raw,
+            &Content::text(message),
+            &MessageOptions::default(),
+        )?;
+        let description = format!(
+            "Send a message of {} blocks with {} to {}",
+            blocks.len(),
+            format_amount(raw, coin),
+            target_address
+        );
+        Ok(Transaction::new(&blocks, description, coin))
+    }
+
+    pub fn change_rep(address: &str, rep_address: &str, coin: &Coin) -> Result<Transaction> {
+        let account = PublicKey::from_address(address)?;
+        let account_info = get_account_info(address, &coin.network.node_url)?;
+        let block = StateBlock::new(
+            Subtype::Change,
+            account,
+            Hash::from_hex(&account_info.frontier)?,
+            PublicKey::from_address(rep_address)?,
+            Amount(get_balance(&account_info)),
+            Hash([0u8; 32]),
+        );
+        let description = format!("Change representative to {}", rep_address);
+        Ok(Transaction::new(&[block], description, coin))
+    }
+
+    // Receives everything receivable by the account as one chain, opening
+    // the account with the network's default representative if needed.
+    pub fn receive_all(address: &str, coin: &Coin) -> Result<Transaction> {
+        let account = PublicKey::from_address(address)?;
+        let receivables = find_incoming(address, &coin.network.node_url, &|_| {})?;
+        if receivables.is_empty() {
+            return Err(Error::Rejected(format!(
+                "{} has nothing to receive",
+                address
+            )));
+        }
+        let (mut previous, mut balance, representative) =
+            match get_account_info(address, &coin.network.node_url) {
+                Ok(account_info) => (
+                    Hash::from_hex(&account_info.frontier)?,
+                    get_balance(&account_info),
+                    PublicKey::from_address(&account_info.representative)?,
+                ),
+                // Not opened yet
+                Err(Error::Rpc(e)) if e == ACCOUNT_NOT_FOUND => (
+                    Hash([0u8; 32]),
+                    0,
+                    PublicKey::from_address(&coin.network.default_rep)?,
+                ),
+                Err(e) => return Err(e),
+            };
+        let mut total = 0;
+        let mut blocks = vec![];
+        for receivable in &receivables {
+            balance += receivable.amount;
+            total += receivable.amount;
+            let block = StateBlock::new(
+                Subtype::Receive,
+                account,
+                previous,
+                representative,
+                Amount(balance),
+                Hash::from_hex(&receivable.hash)?,
+            );
+            previous = block.hash();
+            blocks.push(block);
+        }
+        let description = format!(
+            "Receive {} in {} blocks",
+            format_amount(total, coin),
+            blocks.len()
+        );
+        Ok(Transaction::new(&blocks, description, coin))
+    }
+
+    pub fn read(path: &str) -> Result<Transaction> {
+        let json = fs::read_to_string(path).map_err(|e| Error::Io(e.to_string()))?;
+        let transaction: Transaction =
+            serde_json::from_str(&json).map_err(|e| Error::Decode(e.to_string()))?;
+        if transaction.version > TRANSACTION_VERSION {
+            return Err(Error::Decode(format!(
+                "transaction file version {} is newer than this version of dagchat supports",
+                transaction.version
+            )));
+        }
+        Ok(transaction)
+    }
+
+    pub fn write(&self, path: &str) -> Result<()> {
+        let json = serde_json::to_string_pretty(self).unwrap();
+        fs::write(path, json).map_err(|e| Error::Io(e.to_string()))
+    }
+
+    pub fn is_signed(&self) -> bool {
+        self.blocks
+            .iter()
+            .all(|(_, block)| !block.signature.is_empty())
+    }
+
+    // Parses the blocks, making sure they're for this coin and account
+    // and that each one follows on from the last.
+    pub fn state_blocks(&self, coin: &Coin) -> Result<Vec<StateBlock>> {
+        if self.coin != coin.name {
+            return Err(Error::Decode(format!(
+                "transaction is for {}, not {}",
+                self.coin, coin.name
+            )));
+        }
+        let account = PublicKey::from_address(&self.account)?;
+        let blocks = self
+            .blocks
+            .iter()
+            .map(|(subtype, block)| StateBlock::from_block(block, *subtype))
+            .collect::<Result<Vec<StateBlock>>>()?;
+        if blocks.is_empty() {
+            return Err(Error::Decode(String::from("transaction has no blocks")));
+        }
+        for (i, block) in blocks.iter().enumerate() {
+            if block.account != account {
+                return Err(Error::Tampered(format!(
+                    "block {} is not on {}",
+                    i + 1,
+                    self.account
+                )));
+            }
+            if i > 0 && block.previous != blocks[i - 1].hash() {
+                return Err(Error::Tampered(format!(
+                    "block {} does not follow on from block {}",
+                    i + 1,
+                    i
+                )));
+            }
+        }
+        Ok(blocks)
+    }
+
+    // What the blocks do, worked out from the blocks themselves, since
+    // the description is whatever the file's creator chose to write.
+    // The node goes by balances rather than subtypes, so each block's
+    // subtype is checked against its change in balance. That isn't known
+    // offline for the first block unless it opens the account.
+    pub fn summary(&self, coin: &Coin) -> Result<String> {
+        let blocks = self.state_blocks(coin)?;
+        let mut summary = String::new();
+        let mut previous_balance = if blocks[0].previous.is_zero() {
+            Some(0)
+        } else {
+            None
+        };
+        for (i, block) in blocks.iter().enumerate() {
+            let balance = block.balance.0;
+            let change = previous_balance
+                .map(|previous: u128| (balance.cmp(&previous), balance.abs_diff(previous)));
+            let consistent = match (block.subtype, change) {
+                (Subtype::Send, Some((ordering, _))) => ordering == Ordering::Less,
+                (Subtype::Receive, Some((ordering, _))) => ordering == Ordering::Greater,
+                (Subtype::Change, Some((ordering, _))) => ordering == Ordering::Equal,
+                (_, None) => true,
+            };
+            if !consistent || (block.subtype == Subtype::Change) != block.link.is_zero() {
+                return Err(Error::Tampered(format!(
+                    "block {} doesn't do what its subtype says",
+                    i + 1
+                )));
+            }
+            summary.push_str(&format!("{}. {}", i + 1, block.subtype.as_str()));
+            match block.subtype {
+                Subtype::Send => summary.push_str(&format!(
+                    " to\n{}",
+                    PublicKey(block.link.0).to_address(&coin.prefix)
+                )),
+                Subtype::Receive => {
+                    summary.push_str(&format!(" of block\n{}", block.link.to_hex()))
+                }
+                Subtype::Change => {}
+            }
+            summary.push_str(&format!("\nBalance: {}", format_amount(balance, coin)));
+            match change {
+                Some((Ordering::Less, amount)) => {
+                    summary.push_str(&format!(" (-{})", format_amount(amount, coin)))
+                }
+                Some((Ordering::Greater, amount)) => {
+                    summary.push_str(&format!(" (+{})", format_amount(amount, coin)))
+                }
+                Some((Ordering::Equal, _)) => summary.push_str(" (unchanged)"),
+                None => summary.push_str(" (change can't be checked offline)"),
+            }
+            summary.push_str(&format!(
+                "\nRepresentative:\n{}\n\n",
+                block.representative.to_address(&coin.prefix)
+            ));
+            previous_balance = Some(balance);
+        }
+        Ok(summary.trim_end().to_string())
+    }
+
+    // Signs every block on the offline instance. Work is only generated
+    // locally when with_work is set, otherwise it's left to whoever
+    // publishes the transaction.
+    // progress is ticked up to a total of 1000 over the course of the signing.
+    pub fn sign(
+        &self,
+        private_key_bytes: &[u8; 32],
+        coin: &Coin,
+        with_work: bool,
+        progress: &dyn Fn(usize),
+        control: &WorkControl,
+    ) -> Result<Transaction> {
+        let blocks = self.state_blocks(coin)?;
+        let private_key = SecretKey(*private_key_bytes);
+        if private_key.public_key().to_address(&coin.prefix) != self.account {
+            return Err(Error::Crypto(format!(
+                "the private key is not for {}",
+                self.account
+            )));
+        }
+        // An offline instance can only generate work on its own CPU.
+        let mut offline_coin = coin.clone();
+        offline_coin.network.work_type = if with_work {
+            WorkType::CPU
+        } else {
+            WorkType::BOOMPOW
+        };
+        let x = 1000 / blocks.len();
+        let mut signed = vec![];
+        for block in blocks {
+            signed.push(get_signed_block(
+                block,
+                &private_key,
+                &offline_coin,
+                control,
+            )?);
+            progress(x);
+        }
+        Ok(Transaction {
+            blocks: signed
+                .iter()
+                .map(|block| (block.subtype, block.to_block(&coin.prefix)))
+                .collect(),
+            ..self.clone()
+        })
+    }
+
+    // Publishes a signed transaction, generating any missing work from
+    // the source configured for the coin. Blocks already on chain from
+    // an earlier attempt are skipped.
+    // progress is ticked up to a total of 1000 over the course of the publish.
+    pub fn publish(
+        &self,
+        coin: &Coin,
+        progress: &dyn Fn(usize),
+        control: &WorkControl,
+    ) -> Result<()> {
+        let blocks = self.state_blocks(coin)?;
+        for (i, block) in blocks.iter().enumerate() {
+            if !block.verify_signature() {
+                return Err(Error::Tampered(format!(
+                    "block {} of {} is not signed by {}",
+                    i + 1,
+                    blocks.len(),
+                    self.account
+                )));
+            }
+        }
+        let frontier = match get_account_info(&self.account, &coin.network.node_url) {
+            Ok(account_info) => Hash::from_hex(&account_info.frontier)?,
+            Err(Error::Rpc(e)) if e == ACCOUNT_NOT_FOUND => Hash([0u8; 32]),
+            Err(e) => return Err(e),
+        };
+        let from = if frontier == blocks[0].previous {
+            0
+        } else {
+            match blocks.iter().position(|block| block.hash() == frontier) {
+                Some(idx) => idx + 1,
+                None => {
+                    return Err(Error::Rejected(format!(
+                    "{} has moved on since the transaction was created, so it can't be published",
+                    self.account
+                )))
+                }
+            }
+        };
+        let x = 500 / blocks.len();
+        let mut worked = vec![];
+        for (i, block) in blocks.into_iter().enumerate() {
+            if i < from
+                || coin.network.work_type == WorkType::BOOMPOW
+                || block.has_valid_work(&coin.network)
+            {
+                worked.push(block);
+            } else {
+                worked.push(attach_work(block, coin, control)?);
+            }
+            progress(x);
+        }
+        publish_chain(&worked, from, coin, &|_| progress(x))
+    }
+}
+
+fn checked_balance(account_info: &AccountInfoResponse, raw: u128, address: &str) -> Result<u128> {
+    get_balance(account_info)
+        .checked_sub(raw)
+        .ok_or_else(|| Error::Rejected(format!("{} doesn't have enough balance to send", address)))
+}
+
+fn format_amount(raw: u128, coin: &Coin) -> String {
+    format!(
+        "{}{}",
+        raw_to_whole(&raw.to_string(), &coin.multiplier),
+        coin.ticker
+    )
+}
@@ -1,9 +1,13 @@
-use crate::app::constants::REQ_TIMEOUT;
+use crate::constants::REQ_TIMEOUT;
+use crate::error::Result;
 
-use super::process::post_node;
+use super::process::{parse_response, post_node};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 
+// The node's error for an account that has never been opened.
+pub const ACCOUNT_NOT_FOUND: &str = "Account not found";
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct AccountInfoResponse {
     pub frontier: String,
@@ -18,7 +22,7 @@ pub struct AccountInfoResponse {
     pub representative: String,
 }
 
-pub fn get_account_info(address: &str, node_url: &str) -> Result<AccountInfoResponse, String> {
+pub fn get_account_info(address: &str, node_url: &str) -> Result<AccountInfoResponse> {
     // Change this to AccountInfoRequest struct
     let body_json = json!({
         "action": "account_info",
@@ -28,14 +32,7 @@ pub fn get_account_info(address: &str, node_url: &str) -> Result<AccountInfoResp
 
     let body = body_json.to_string();
     let resp_string = post_node(body, node_url, REQ_TIMEOUT)?;
-    let accountinfo = serde_json::from_str(&resp_string);
-    match accountinfo {
-        Ok(accountinfo) => Ok(accountinfo),
-        Err(e) => {
-            //eprintln!("Error getting account info: {}", e);
-            Err(e.to_string())
-        }
-    }
+    parse_response(&resp_string)
 }
 
 pub fn get_balance(info: &AccountInfoResponse) -> u128 {
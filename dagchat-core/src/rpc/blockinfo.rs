@@ -1,10 +1,11 @@
-use crate::app::constants::REQ_TIMEOUT;
+use crate::constants::REQ_TIMEOUT;
+use crate::error::Result;
 
 use super::process::post_node;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Block {
     #[serde(rename = "type")]
     pub type_name: String,
@@ -14,7 +15,7 @@ pub struct Block {
     pub representative: String,
     pub balance: String,
     pub link: String,
-    #[serde(skip_serializing_if = "String::is_empty")]
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub work: String,
     pub signature: String,
 }
@@ -50,7 +51,7 @@ pub struct BlocksInfoResponse {
     pub blocks: BlocksResponse,
 }
 
-pub fn get_blocks_info(hashes: Vec<String>, node_url: &str) -> Result<BlocksInfoResponse, String> {
+pub fn get_blocks_info(hashes: Vec<String>, node_url: &str) -> Result<BlocksInfoResponse> {
     let request = BlocksRequest {
         action: String::from("blocks_info"),
         json_block: true,
@@ -61,7 +62,8 @@ pub fn get_blocks_info(hashes: Vec<String>, node_url: &str) -> Result<BlocksInfo
     //eprintln!("Body: {}", body);
     let response = post_node(body, node_url, REQ_TIMEOUT)?;
 
-    let blocks_info_response: Result<BlocksInfoResponse, _> = serde_json::from_str(&response);
+    let blocks_info_response: serde_json::Result<BlocksInfoResponse> =
+        serde_json::from_str(&response);
     match blocks_info_response {
         Ok(blocks_info_response) => Ok(blocks_info_response),
         // If deserialisation failed, either there were no blocks
@@ -0,0 +1,27 @@
+use super::accountinfo::{get_balance, AccountInfoResponse};
+use super::process::publish_block;
+use crate::coin::Coin;
+use crate::crypto::blocks::{get_signed_block, Amount, Hash, StateBlock, Subtype};
+use crate::crypto::keys::{PublicKey, SecretKey};
+use crate::crypto::pow::WorkControl;
+use crate::error::Result;
+
+pub fn change_rep(
+    private_key_bytes: &[u8; 32],
+    account_info: AccountInfoResponse,
+    rep_address: &str,
+    coin: &Coin,
+    control: &WorkControl,
+) -> Result<String> {
+    let private_key = SecretKey(*private_key_bytes);
+    let block = StateBlock::new(
+        Subtype::Change,
+        private_key.public_key(),
+        Hash::from_hex(&account_info.frontier)?,
+        PublicKey::from_address(rep_address)?,
+        Amount(get_balance(&account_info)),
+        Hash([0u8; 32]),
+    );
+    let block = get_signed_block(block, &private_key, coin, control)?;
+    publish_block(&block, coin)
+}
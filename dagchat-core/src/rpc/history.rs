@@ -0,0 +1,152 @@
+use crate::constants::REQ_TIMEOUT;
+use crate::error::Result;
+
+use super::blockinfo::Block;
+use super::process::{parse_response, post_node};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug)]
+struct HistoryRequest {
+    action: String,
+    account: String,
+    count: u64,
+    head: String,
+    reverse: bool,
+    raw: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct RecentHistoryRequest {
+    action: String,
+    account: String,
+    count: u64,
+    raw: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct HistoryPageRequest {
+    action: String,
+    account: String,
+    count: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    head: Option<String>,
+    raw: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct HistoryBlock {
+    pub hash: String,
+    #[serde(default)]
+    pub subtype: String,
+    #[serde(default)]
+    pub amount: String,
+    #[serde(default)]
+    pub height: String,
+    #[serde(default)]
+    pub local_timestamp: String,
+    #[serde(flatten)]
+    pub contents: Block,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct RecentHistoryResponse {
+    #[serde(default)]
+    history: Vec<serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct HistoryPageResponse {
+    // An empty string rather than an array for an unopened account.
+    #[serde(default)]
+    history: serde_json::Value,
+    // The next older block, where the following page starts.
+    #[serde(default)]
+    previous: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct HistoryResponse {
+    account: String,
+    history: Vec<Block>,
+    #[serde(default)]
+    next: String,
+}
+
+pub fn get_history(
+    target_address: &str,
+    head: &str,
+    length: u64,
+    node_url: &str,
+) -> Result<Vec<Block>> {
+    let request = HistoryRequest {
+        action: String::from("account_history"),
+        account: String::from(target_address),
+        count: length,
+        head: String::from(head),
+        reverse: true,
+        raw: true,
+    };
+    let body = serde_json::to_string(&request).unwrap();
+    let response = post_node(body, node_url, REQ_TIMEOUT)?;
+    let history_info: HistoryResponse = parse_response(&response)?;
+    Ok(history_info.history)
+}
+
+// The account's most recent state blocks, newest first. Legacy blocks
+// don't carry every field of a state block and are skipped.
+pub fn get_recent_history(
+    target_address: &str,
+    length: u64,
+    node_url: &str,
+) -> Result<Vec<HistoryBlock>> {
+    let request = RecentHistoryRequest {
+        action: String::from("account_history"),
+        account: String::from(target_address),
+        count: length,
+        raw: true,
+    };
+    let body = serde_json::to_string(&request).unwrap();
+    let response = post_node(body, node_url, REQ_TIMEOUT)?;
+    let history_info: RecentHistoryResponse = parse_response(&response)?;
+    Ok(history_info
+        .history
+        .into_iter()
+        .filter_map(|block| serde_json::from_value(block).ok())
+        .collect())
+}
+
+// A page of the account's state blocks, newest first, starting at head
+// or the frontier. Also returns the hash the next page starts at, which
+// is None once the open block has been reached.
+pub fn get_history_page(
+    target_address: &str,
+    head: Option<String>,
+    length: u64,
+    node_url: &str,
+) -> Result<(Vec<HistoryBlock>, Option<String>)> {
+    let request = HistoryPageRequest {
+        action: String::from("account_history"),
+        account: String::from(target_address),
+        count: length,
+        head,
+        raw: true,
+    };
+    let body = serde_json::to_string(&request).unwrap();
+    let response = post_node(body, node_url, REQ_TIMEOUT)?;
+    let history_info: HistoryPageResponse = parse_response(&response)?;
+    let next = if history_info.previous.is_empty() {
+        None
+    } else {
+        Some(history_info.previous)
+    };
+    Ok((
+        history_info
+            .history
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|block| serde_json::from_value(block.clone()).ok())
+            .collect(),
+        next,
+    ))
+}
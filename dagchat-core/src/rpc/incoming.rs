@@ -0,0 +1,205 @@
+use super::{
+    blockinfo::{get_blocks_info, BlockResponse},
+    process::*,
+};
+use crate::constants::REQ_TIMEOUT;
+use crate::crypto::blocks::{Hash, StateBlock, Subtype};
+use crate::crypto::keys::PublicKey;
+use crate::error::{Error, Result};
+use crate::messages::structs::Message;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ReceivableRequest {
+    pub action: String,
+    pub account: String,
+    pub count: String,
+    pub source: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ReceivableResponse {
+    pub blocks: ReceivableBlocks,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ReceivableBlocks {
+    #[serde(flatten)]
+    pub data: HashMap<String, ReceivableBlock>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ReceivableBlock {
+    pub amount: String,
+    pub source: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Receivable {
+    pub hash: String,
+    pub message: Option<Message>,
+    pub amount: u128,
+    // Used for seeing message sender in app
+    pub source: String,
+}
+
+// progress is ticked up to a total of 800 over the course of the lookup.
+pub fn find_incoming(
+    target_address: &str,
+    node_url: &str,
+    progress: &dyn Fn(usize),
+) -> Result<Vec<Receivable>> {
+    let request = ReceivableRequest {
+        action: String::from("pending"),
+        account: String::from(target_address),
+        count: String::from("50"),
+        source: true,
+    };
+
+    let body = serde_json::to_string(&request).unwrap();
+    let response = post_node(body, node_url, REQ_TIMEOUT)?;
+    progress(200);
+
+    let receivables: serde_json::Result<ReceivableResponse> = serde_json::from_str(&response);
+    let receivables = match receivables {
+        Ok(receivables) => receivables,
+        // If deserialisation failed, either there were no blocks
+        // Or an different error was encountered.
+        Err(error) => {
+            // If the error was missing the blocks field, then it
+            // likely wasn't due to deserialising a response returned
+            // as a result of having no receivables; it was instead a network error.
+            if error.to_string().contains("missing field") {
+                return Err(Error::Decode(error.to_string() + ": " + &response));
+            } else {
+                return Ok(vec![]);
+            }
+        }
+    };
+
+    let receivable_blocks = receivables.blocks.data;
+    let mut head_hashes: Vec<String> = vec![];
+    for block in &receivable_blocks {
+        head_hashes.push(block.0.clone());
+    }
+    progress(50);
+    let head_blocks_info = get_blocks_info(head_hashes, node_url)?;
+    progress(200);
+    let mut raw_head_blocks = head_blocks_info.blocks.data;
+    let mut root_hashes: Vec<String> = vec![];
+
+    // Legacy blocks have no representative to be a root.
+    for block in raw_head_blocks.values() {
+        if let Ok(hash) = root_hash(block) {
+            root_hashes.push(hash);
+        }
+    }
+    progress(50);
+    let root_blocks_info = get_blocks_info(root_hashes, node_url)?;
+    progress(100);
+    let raw_root_blocks = root_blocks_info.blocks.data;
+
+    let target = Hash::from(PublicKey::from_address(target_address)?);
+    let mut incoming: Vec<Receivable> = vec![];
+    let x = 200usize / receivable_blocks.len();
+    for receivable in receivable_blocks {
+        // A send that can't be checked, such as a legacy block, is still
+        // listed so that it can be received, just without a message.
+        let message = raw_head_blocks
+            .remove(&receivable.0)
+            .and_then(|head_block| {
+                checked_message(&receivable.0, head_block, &target, &raw_root_blocks).ok()
+            })
+            .flatten();
+        incoming.push(Receivable {
+            hash: receivable.0,
+            amount: receivable.1.amount.parse().unwrap(),
+            source: receivable.1.source,
+            message,
+        });
+        progress(x);
+    }
+    Ok(incoming)
+}
+
+// Never trust the node: the head must hash to the receivable's hash,
+// be signed by its account and actually pay this account.
+fn checked_message(
+    hash: &str,
+    head_block: BlockResponse,
+    target: &Hash,
+    root_blocks: &HashMap<String, BlockResponse>,
+) -> Result<Option<Message>> {
+    let head = verified_head(hash, &head_block)?;
+    if head.link != *target {
+        return Err(Error::Tampered(format!(
+            "block {} is not a send to this account",
+            hash
+        )));
+    }
+    head_message(hash, &head, head_block, root_blocks)
+}
+
+// The hash a send's representative would be if it were the head of a
+// message.
+pub fn root_hash(head_block: &BlockResponse) -> Result<String> {
+    let rep = &head_block.contents.representative;
+    Ok(Hash::from(PublicKey::from_address(rep)?).to_hex())
+}
+
+// Checks that a block from the node hashes to the hash it was asked
+// for and is signed by its account.
+pub fn verified_head(hash: &str, head_block: &BlockResponse) -> Result<StateBlock> {
+    let head = StateBlock::from_signed_block(&head_block.contents, Subtype::Send)?;
+    if head.hash() != Hash::from_hex(hash)? {
+        return Err(Error::Tampered(format!(
+            "block {} does not match its contents",
+            hash
+        )));
+    }
+    Ok(head)
+}
+
+// The message a verified send is the head of, if its representative is
+// the hash of an earlier block on the same chain. root_blocks holds the
+// blocks_info of the candidate roots.
+pub fn head_message(
+    head_hash: &str,
+    head: &StateBlock,
+    head_block: BlockResponse,
+    root_blocks: &HashMap<String, BlockResponse>,
+) -> Result<Option<Message>> {
+    let hash = Hash::from(head.representative).to_hex();
+    let root_block = match root_blocks.get(&hash) {
+        Some(root_block) => root_block,
+        None => return Ok(None),
+    };
+    let root = StateBlock::from_signed_block(&root_block.contents, Subtype::Change)?;
+    if root.hash().to_hex() != hash {
+        return Err(Error::Tampered(format!(
+            "message root {} does not match its contents",
+            hash
+        )));
+    }
+    // A representative that happens to be the hash of a block on
+    // another account's chain doesn't start a message.
+    if root.account != head.account {
+        return Ok(None);
+    }
+    let head_height: u64 = head_block.height.parse().unwrap();
+    let root_height: u64 = root_block.height.parse().unwrap();
+    if root_height >= head_height {
+        return Err(Error::Tampered(format!(
+            "message root {} is not below its head {}",
+            hash, head_hash
+        )));
+    }
+    Ok(Some(Message {
+        blocks: head_height - root_height,
+        head: head_block,
+        root_hash: hash,
+        plaintext: String::from(""),
+        attachment: None,
+    }))
+}
@@ -0,0 +1,153 @@
+use super::blockinfo::Block;
+use crate::coin::Coin;
+use crate::constants::REQ_TIMEOUT;
+use crate::crypto::blocks::{StateBlock, Subtype};
+use crate::error::{Error, Result};
+use crate::network::*;
+use serde;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+#[derive(Serialize, Deserialize, Debug)]
+struct ProcessRequest {
+    action: String,
+    json_block: String,
+    subtype: Subtype,
+    block: Block,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct BoomPowProcessRequest {
+    action: String,
+    json_block: String,
+    subtype: Subtype,
+    do_work: bool,
+    block: Block,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct ProcessResponse {
+    hash: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct ErrorResponse {
+    error: String,
+}
+
+pub fn post_node(body: String, node_url: &str, timeout: u64) -> Result<String> {
+    let client = reqwest::blocking::Client::new();
+    let res = client
+        .post(node_url)
+        .timeout(Duration::from_secs(timeout))
+        .header("Content-Type", "application/json")
+        .header("Accept", "application/json")
+        .body(body)
+        .send();
+
+    //let x = res.unwrap().text().unwrap();
+    //eprintln!("Request:{}\n\nResponse:{}\n\n", body, x);
+    //return Ok(x);
+    match res {
+        Ok(res) => {
+            if !res.status().is_success() {
+                //eprintln!("Issue posting to node. Status: {}", res.status());
+                return Err(Error::Network(res.status().to_string()));
+            }
+            res.text().map_err(|e| Error::Network(e.to_string()))
+        }
+        Err(e) => Err(Error::Network(e.to_string())),
+    }
+}
+
+// Deserialises a node response, reporting an {"error": ...} body
+// from the node as an RPC error rather than a decoding failure.
+pub fn parse_response<T: DeserializeOwned>(response: &str) -> Result<T> {
+    match serde_json::from_str(response) {
+        Ok(parsed) => Ok(parsed),
+        Err(e) => {
+            if let Ok(error_response) = serde_json::from_str::<ErrorResponse>(response) {
+                Err(Error::Rpc(error_response.error))
+            } else {
+                Err(Error::Decode(e.to_string()))
+            }
+        }
+    }
+}
+
+pub fn publish_block(block: &StateBlock, coin: &Coin) -> Result<String> {
+    let network = &coin.network;
+    // Work is only left to the node with BoomPow; anything else must
+    // already meet the threshold or the node would reject the block.
+    if network.work_type != WorkType::BOOMPOW && !block.has_valid_work(network) {
+        return Err(Error::Work(format!(
+            "work for block {} is missing or below the threshold",
+            block.hash().to_hex()
+        )));
+    }
+    let sub = block.subtype;
+    let block = block.to_block(&coin.prefix);
+    let response =
+        if network.work_type == WorkType::CPU || network.work_type == WorkType::WORK_SERVER {
+            let body = serde_json::to_string(&ProcessRequest {
+                action: String::from("process"),
+                json_block: String::from("true"),
+                subtype: sub,
+                block,
+            })
+            .unwrap();
+            post_node(body, &network.node_url, REQ_TIMEOUT)?
+        } else {
+            let body = serde_json::to_string(&BoomPowProcessRequest {
+                action: String::from("process"),
+                json_block: String::from("true"),
+                subtype: sub,
+                do_work: true,
+                block,
+            })
+            .unwrap();
+            post_node(body, &network.appditto_node_url, REQ_TIMEOUT)?
+        };
+    let process_response: ProcessResponse = parse_response(&response)?;
+    Ok(process_response.hash)
+}
+
+// Publishes blocks that were built as one chain in order, starting
+// from the block at index from. Stops at the first block the node
+// doesn't accept, reporting exactly how much of the chain made it on
+// to the network. on_published is given the number of blocks on chain
+// after each one is accepted.
+pub fn publish_chain(
+    blocks: &[StateBlock],
+    from: usize,
+    coin: &Coin,
+    on_published: &dyn Fn(usize),
+) -> Result<()> {
+    for (i, block) in blocks.iter().enumerate().skip(from) {
+        let expected = block.hash().to_hex();
+        let published = match publish_block(block, coin) {
+            Ok(hash) if hash.eq_ignore_ascii_case(&expected) => Ok(()),
+            Ok(hash) => Err(format!("the node reported hash {}", hash)),
+            Err(e) => Err(e.to_string()),
+        };
+        if let Err(cause) = published {
+            let on_chain = if i == 0 {
+                String::from("No blocks were published")
+            } else {
+                format!("Blocks 1 to {} were published", i)
+            };
+            return Err(Error::Rejected(format!(
+                "{} block {} of {} ({}) was not accepted: {}. {}.",
+                block.subtype.as_str(),
+                i + 1,
+                blocks.len(),
+                expected,
+                cause,
+                on_chain
+            )));
+        }
+        on_published(i + 1);
+    }
+    Ok(())
+}
@@ -1,6 +1,7 @@
+use crate::error::{Error, Result};
 use serde::{Deserialize, Serialize};
 
-use super::process::post_node;
+use super::process::{parse_response, post_node};
 
 #[derive(Serialize, Deserialize, Debug)]
 struct WorkRequest {
@@ -29,11 +30,7 @@ struct ValidateResponse {
     valid: String,
 }
 
-pub fn get_server_work(
-    hash: &[u8; 32],
-    difficulty: &str,
-    server_url: &str,
-) -> Result<String, String> {
+pub fn get_server_work(hash: &[u8; 32], difficulty: &str, server_url: &str) -> Result<String> {
     let body = serde_json::to_string(&WorkRequest {
         action: String::from("work_generate"),
         hash: hex::encode(hash),
@@ -41,17 +38,17 @@ pub fn get_server_work(
     })
     .unwrap();
     let response = post_node(body, server_url, 30)?;
-    let work_response: Result<WorkResponse, _> = serde_json::from_str(&response);
+    let work_response: Result<WorkResponse> = parse_response(&response);
     match work_response {
         Ok(work_response) => Ok(work_response.work),
         Err(e) => {
-            //eprintln!("Error getting account info: {}", e);
-            Err(e.to_string())
+            //eprintln!("Error getting work: {}", e);
+            Err(Error::Work(e.to_string()))
         }
     }
 }
 
-pub fn test_work_server(server_url: &str) -> Result<String, String> {
+pub fn test_work_server(server_url: &str) -> Result<String> {
     let body = serde_json::to_string(&ValidateRequest {
         action: String::from("work_validate"),
         difficulty: String::from("FFFFFFF800000000"),
@@ -60,20 +57,20 @@ pub fn test_work_server(server_url: &str) -> Result<String, String> {
     })
     .unwrap();
     let response = post_node(body, server_url, 5)?;
-    let validate_response: Result<ValidateResponse, _> = serde_json::from_str(&response);
+    let validate_response: Result<ValidateResponse> = parse_response(&response);
     match validate_response {
         Ok(validate_response) => {
             if validate_response.valid == "1" {
                 Ok(String::from("Success."))
             } else {
-                Err(String::from(
+                Err(Error::Work(String::from(
                     "the response from the work server was incorrect.",
-                ))
+                )))
             }
         }
         Err(e) => {
             //eprintln!("Error testing work server: {}", e);
-            Err(e.to_string())
+            Err(e)
         }
     }
 }
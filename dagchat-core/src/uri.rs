@@ -0,0 +1,89 @@
+use crate::crypto::address::validate_address;
+use crate::error::{Error, Result};
+
+// A payment request such as nano:nano_1...?amount=1000&label=Shop, with
+// the amount in raw. Banano uses the ban: scheme.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PaymentUri {
+    pub address: String,
+    pub amount: Option<u128>,
+    pub label: Option<String>,
+    pub message: Option<String>,
+}
+
+impl PaymentUri {
+    pub fn new(address: &str) -> PaymentUri {
+        PaymentUri {
+            address: String::from(address),
+            ..Default::default()
+        }
+    }
+
+    pub fn is_uri(text: &str) -> bool {
+        let text = text.trim().to_lowercase();
+        text.starts_with("nano:") || text.starts_with("ban:")
+    }
+
+    pub fn parse(uri: &str) -> Result<PaymentUri> {
+        let uri = uri.trim();
+        let (scheme, rest) = uri
+            .split_once(':')
+            .ok_or_else(|| Error::Decode(String::from("not a payment URI")))?;
+        let scheme = scheme.to_lowercase();
+        if scheme != "nano" && scheme != "ban" {
+            return Err(Error::Decode(format!("unsupported URI scheme: {}", scheme)));
+        }
+        // Some wallets write nano://address.
+        let rest = rest.trim_start_matches("//");
+        let (address, query) = rest.split_once('?').unwrap_or((rest, ""));
+        // Pasted URIs can hold anything, and addresses are only ever ASCII.
+        if !address.is_ascii()
+            || !validate_address(address)
+            || !address.starts_with(&format!("{}_", scheme))
+        {
+            return Err(Error::Decode(format!(
+                "invalid {} address: {}",
+                scheme, address
+            )));
+        }
+        let mut payment = PaymentUri::new(address);
+        for (key, value) in form_urlencoded::parse(query.as_bytes()) {
+            match key.as_ref() {
+                "amount" => {
+                    let amount = value
+                        .parse()
+                        .map_err(|_| Error::Decode(format!("invalid raw amount: {}", value)))?;
+                    payment.amount = Some(amount);
+                }
+                "label" => payment.label = Some(value.into_owned()),
+                "message" => payment.message = Some(value.into_owned()),
+                // Other parameters, such as a representative, don't
+                // apply to a send.
+                _ => {}
+            }
+        }
+        Ok(payment)
+    }
+
+    pub fn to_uri(&self) -> String {
+        let scheme = self.address.split('_').next().unwrap_or("nano");
+        let mut query = form_urlencoded::Serializer::new(String::new());
+        if let Some(amount) = self.amount {
+            query.append_pair("amount", &amount.to_string());
+        }
+        if let Some(label) = &self.label {
+            query.append_pair("label", label);
+        }
+        if let Some(message) = &self.message {
+            query.append_pair("message", message);
+        }
+        let query = query.finish();
+        if query.is_empty() {
+            format!("{}:{}", scheme, self.address)
+        } else {
+            // Spaces are written %20 rather than +, which not every
+            // wallet decodes.
+            format!("{}:{}?{}", scheme, self.address, query.replace('+', "%20"))
+        }
+    }
+}
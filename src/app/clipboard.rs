@@ -1,3 +1,4 @@
+use super::coin::Colour;
 use super::constants::colours::{OFF_WHITE, RED};
 use super::userdata::UserData;
 use cursive::traits::Resizable;
@@ -17,7 +18,7 @@ pub fn copy_to_clip(s: &mut Cursive, string: String) {
         let mut content = StyledString::styled(format!("{}\n", string), OFF_WHITE);
         content.append(StyledString::styled(
             "was successfully copied to your clipboard.",
-            data.coins[data.coin_idx].colour,
+            data.coins[data.coin_idx].colour(),
         ));
         s.add_layer(
             Dialog::around(TextView::new(content))
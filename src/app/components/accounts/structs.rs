@@ -1,8 +1,7 @@
-use crate::app::components::{
-    messages::structs::SavedMessage, receive::structs::Receivable, wallets::structs::Wallet,
-};
-use crate::crypto::address::get_address;
-use crate::crypto::keys::get_private_key;
+use crate::app::components::{messages::structs::SavedMessage, wallets::structs::Wallet};
+use dagchat_core::crypto::address::get_address;
+use dagchat_core::crypto::keys::{derive_private_key, get_private_key};
+use dagchat_core::rpc::incoming::Receivable;
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -31,7 +30,12 @@ impl Account {
                 messages: Ok(vec![]),
             }
         } else {
-            let (private_key, public_key) = Account::get_keypair(&wallet.seed, index);
+            let private_key = if wallet.is_bip39() {
+                derive_private_key(&wallet.bip39_seed, index, wallet.derivation, prefix)
+            } else {
+                get_private_key(&wallet.seed, index)
+            };
+            let public_key = Account::get_public_key(&private_key);
             Account {
                 index,
                 private_key,
@@ -44,11 +48,6 @@ impl Account {
         }
     }
 
-    pub fn get_keypair(seed: &[u8; 32], index: u32) -> ([u8; 32], [u8; 32]) {
-        let private_key = get_private_key(seed, index);
-        let public_key = Account::get_public_key(&private_key);
-        (private_key, public_key)
-    }
     pub fn get_public_key(private_key: &[u8; 32]) -> [u8; 32] {
         let dalek = ed25519_dalek::SecretKey::from_bytes(private_key).unwrap();
         let public_key = ed25519_dalek::PublicKey::from(&dalek);
@@ -1,4 +1,7 @@
-use crate::app::components::{messages::load::load_messages, receive::load::load_receivables};
+use crate::app::components::{
+    messages::load::load_messages, receive::load::load_receivables,
+    work::precompute::precompute_work,
+};
 use crate::app::userdata::UserData;
 use cursive::views::{Dialog, OnEventView, SelectView};
 use cursive::Cursive;
@@ -25,6 +28,9 @@ pub fn load_current_account(s: &mut Cursive) {
     let data = &mut s.user_data::<UserData>().unwrap();
     //eprintln!("Loaded messages: {:?}", messages);
     let wallet = &mut data.wallets[data.wallet_idx];
-    wallet.accounts[wallet.acc_idx].messages = messages;
+    let account = &mut wallet.accounts[wallet.acc_idx];
+    account.messages = messages;
+    let address = account.address.clone();
     load_receivables(s);
+    precompute_work(s, vec![address]);
 }
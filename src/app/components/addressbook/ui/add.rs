@@ -1,16 +1,17 @@
 use super::primary::show_addressbook;
+use crate::app::coin::Colour;
 use crate::app::{
     clipboard::paste_clip, components::storage::save::save_to_storage, constants::colours::RED,
     helpers::go_back, themes::get_subtitle_colour, userdata::UserData,
 };
-use crate::crypto::address::validate_address;
 use cursive::traits::{Nameable, Resizable};
 use cursive::views::{Button, Dialog, DummyView, LinearLayout, TextArea, TextView, ViewRef};
 use cursive::{utils::markup::StyledString, Cursive};
+use dagchat_core::crypto::address::validate_address;
 
 pub fn add_addressbook(s: &mut Cursive) {
     let data = &s.user_data::<UserData>().unwrap();
-    let sub_title_colour = get_subtitle_colour(data.coins[data.coin_idx].colour);
+    let sub_title_colour = get_subtitle_colour(data.coins[data.coin_idx].colour());
     let form_content = LinearLayout::vertical()
         .child(TextView::new(StyledString::styled(
             "Name",
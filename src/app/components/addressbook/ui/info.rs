@@ -1,3 +1,5 @@
+use crate::app::coin::Colour;
+use crate::app::components::inbox::ui::verifymessage::show_verify_message;
 use crate::app::{
     clipboard::copy_to_clip, constants::colours::OFF_WHITE, helpers::go_back, userdata::UserData,
 };
@@ -36,7 +38,7 @@ pub fn contact_info(s: &mut Cursive) {
         let non_prefix = &address[idx_of_ + 1..];
         address = network_prefix.to_owned() + non_prefix
     }
-    let colour = data.coins[data.coin_idx].colour;
+    let colour = data.coins[data.coin_idx].colour();
 
     let mut contact_info = StyledString::styled("Name\n", colour);
     contact_info.append(StyledString::styled(name, OFF_WHITE));
@@ -45,6 +47,12 @@ pub fn contact_info(s: &mut Cursive) {
     s.add_layer(
         Dialog::text(contact_info)
             .button("Back", go_back)
-            .button("Copy address", move |s| copy_to_clip(s, address.clone())),
+            .button("Copy address", {
+                let address = address.clone();
+                move |s| copy_to_clip(s, address.clone())
+            })
+            .button("Verify message", move |s| {
+                show_verify_message(s, address.clone())
+            }),
     );
 }
@@ -36,7 +36,14 @@ pub fn select_addressbook(s: &mut Cursive, name: &str) {
         view.set_visible(true);
     })
     .unwrap();
+    // A message can have several recipients, so contacts are added to
+    // any already there.
+    let with_message = s.call_on_name("message", |_: &mut TextArea| {}).is_some();
     s.call_on_name("address", |view: &mut TextArea| {
+        let current = view.get_content().trim();
+        if with_message && !current.is_empty() && !current.contains(&address) {
+            address = format!("{}\n{}", current, address);
+        }
         view.set_content(address);
     })
     .unwrap();
@@ -1,15 +1,18 @@
 use super::primary::show_inbox;
+use crate::app::coin::Colour;
 use crate::app::components::addressbook::ui::primary::show_addressbook;
+use crate::app::components::work::precompute::{cancel_precompute, precompute_work};
 use crate::app::constants::colours::RED;
 use crate::app::{clipboard::paste_clip, themes::get_subtitle_colour, userdata::UserData};
-use crate::crypto::address::validate_address;
-use crate::rpc::{accountinfo::get_account_info, changerep::change_rep};
 use cursive::traits::{Nameable, Resizable};
 use cursive::utils::markup::StyledString;
 use cursive::views::{
     Button, Dialog, DummyView, HideableView, LinearLayout, TextArea, TextView, ViewRef,
 };
 use cursive::Cursive;
+use dagchat_core::crypto::address::validate_address;
+use dagchat_core::crypto::pow::WorkControl;
+use dagchat_core::rpc::{accountinfo::get_account_info, changerep::change_rep};
 
 pub fn show_change_rep(s: &mut Cursive) {
     s.pop_layer();
@@ -19,7 +22,7 @@ pub fn show_change_rep(s: &mut Cursive) {
     let private_key = account.private_key;
     let coin = data.coins[data.coin_idx].clone();
     let address = account.address.clone();
-    let sub_title_colour = get_subtitle_colour(coin.colour);
+    let sub_title_colour = get_subtitle_colour(coin.colour());
     s.add_layer(
         HideableView::new(
         Dialog::around(
@@ -64,7 +67,8 @@ pub fn show_change_rep(s: &mut Cursive) {
                         return;
                     }
                     let account_info = account_info_opt.unwrap();
-                    if let Err(error) = change_rep(&private_key, account_info, &rep_address, &coin) {
+                    cancel_precompute(s);
+                    if let Err(error) = change_rep(&private_key, account_info, &rep_address, &coin, &WorkControl::default()) {
                         s.add_layer(
                             Dialog::info(StyledString::styled(format!("Failed to change representative. Error: {}", error), RED)),
                         );
@@ -72,6 +76,7 @@ pub fn show_change_rep(s: &mut Cursive) {
                     }
                     s.pop_layer();
                     show_inbox(s);
+                    precompute_work(s, vec![address.clone()]);
                     s.add_layer(Dialog::info("Successfully changed representative!"));
                 }))
                 .child(Button::new("Back", show_inbox))),
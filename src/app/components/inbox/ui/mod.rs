@@ -1,3 +1,6 @@
 pub mod changerep;
 pub mod signmessage;
 pub mod primary;
+pub mod requestpayment;
+pub mod restorerep;
+pub mod verifymessage;
\ No newline at end of file
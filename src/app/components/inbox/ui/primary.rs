@@ -1,8 +1,12 @@
 use super::changerep::show_change_rep;
+use super::requestpayment::show_request_payment;
 use super::signmessage::show_sign_message;
+use super::verifymessage::show_verify_message;
+use crate::app::coin::Colour;
 use crate::app::{
     clipboard::copy_to_clip,
     constants::{SHOW_TO_DP, VERSION},
+    qr::show_qr,
     userdata::UserData,
 };
 use crate::app::{
@@ -17,7 +21,6 @@ use crate::app::{
     },
     constants::EMPTY_MSG,
 };
-use crate::crypto::conversions::display_to_dp;
 use cursive::utils::markup::StyledString;
 use cursive::views::{Button, Dialog, DummyView, LinearLayout, SelectView, TextView};
 use cursive::Cursive;
@@ -25,6 +28,7 @@ use cursive::{
     traits::{Nameable, Resizable, Scrollable},
     views::HideableView,
 };
+use dagchat_core::crypto::conversions::display_to_dp;
 
 pub fn show_inbox(s: &mut Cursive) {
     s.set_autorefresh(false);
@@ -32,6 +36,7 @@ pub fn show_inbox(s: &mut Cursive) {
     let data: UserData = s.take_user_data().unwrap();
     let wallet = &data.wallets[data.wallet_idx];
     let address = wallet.accounts[wallet.acc_idx].address.clone();
+    let qr_address = address.clone();
 
     let bal = display_to_dp(
         wallet.accounts[wallet.acc_idx].balance,
@@ -43,7 +48,7 @@ pub fn show_inbox(s: &mut Cursive) {
     let top_content = LinearLayout::horizontal().child(
         TextView::new(StyledString::styled(
             bal_text,
-            data.coins[data.coin_idx].colour,
+            data.coins[data.coin_idx].colour(),
         ))
         .with_name("balance"),
     );
@@ -101,7 +106,14 @@ pub fn show_inbox(s: &mut Cursive) {
         .child(Button::new("Copy address", move |s| {
             copy_to_clip(s, address.clone())
         }))
+        .child(Button::new("Address QR", move |s| {
+            show_qr(s, "Address", &qr_address)
+        }))
+        .child(Button::new("Request payment", show_request_payment))
         .child(Button::new("Sign message", show_sign_message))
+        .child(Button::new("Verify message", |s| {
+            show_verify_message(s, String::new())
+        }))
         .child(Button::new("Change rep", show_change_rep))
         .child(DummyView)
         .child(Button::new("Back", show_accounts));
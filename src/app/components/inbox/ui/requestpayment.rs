@@ -0,0 +1,84 @@
+use crate::app::coin::Colour;
+use crate::app::{
+    clipboard::copy_to_clip, helpers::go_back, qr::show_qr, themes::get_subtitle_colour,
+    userdata::UserData,
+};
+use cursive::traits::{Nameable, Resizable};
+use cursive::utils::markup::StyledString;
+use cursive::views::{Dialog, DummyView, EditView, LinearLayout, TextView};
+use cursive::Cursive;
+use dagchat_core::crypto::conversions::whole_to_raw;
+use dagchat_core::uri::PaymentUri;
+
+pub fn show_request_payment(s: &mut Cursive) {
+    let data = &s.user_data::<UserData>().unwrap();
+    let coin = &data.coins[data.coin_idx];
+    let sub_title_colour = get_subtitle_colour(coin.colour());
+    let amount_title = format!("Optional {}", coin.ticker.trim());
+    s.add_layer(
+        Dialog::around(
+            LinearLayout::vertical()
+                .child(DummyView)
+                .child(TextView::new(StyledString::styled(
+                    amount_title,
+                    sub_title_colour,
+                )))
+                .child(EditView::new().with_name("amount"))
+                .child(DummyView)
+                .child(TextView::new(StyledString::styled(
+                    "Optional label",
+                    sub_title_colour,
+                )))
+                .child(EditView::new().with_name("label"))
+                .child(DummyView)
+                .child(TextView::new(StyledString::styled(
+                    "Optional message",
+                    sub_title_colour,
+                )))
+                .child(EditView::new().with_name("message")),
+        )
+        .button("Copy URI", |s| {
+            if let Some(uri) = build_uri(s) {
+                s.pop_layer();
+                copy_to_clip(s, uri);
+            }
+        })
+        .button("Show QR", |s| {
+            if let Some(uri) = build_uri(s) {
+                show_qr(s, "Payment request", &uri);
+            }
+        })
+        .button("Back", go_back)
+        .title("Request payment")
+        .max_width(80),
+    );
+}
+
+fn build_uri(s: &mut Cursive) -> Option<String> {
+    let mut fields = vec![];
+    for name in ["amount", "label", "message"] {
+        let content = s
+            .call_on_name(name, |view: &mut EditView| view.get_content())
+            .unwrap();
+        fields.push(String::from(content.trim()));
+    }
+    let data = &s.user_data::<UserData>().unwrap();
+    let wallet = &data.wallets[data.wallet_idx];
+    let mut payment = PaymentUri::new(&wallet.accounts[wallet.acc_idx].address);
+    if !fields[0].is_empty() {
+        match whole_to_raw(fields[0].clone(), &data.coins[data.coin_idx].multiplier) {
+            Some(raw) => payment.amount = Some(raw),
+            None => {
+                s.add_layer(Dialog::info("The amount was invalid."));
+                return None;
+            }
+        }
+    }
+    if !fields[1].is_empty() {
+        payment.label = Some(fields[1].clone());
+    }
+    if !fields[2].is_empty() {
+        payment.message = Some(fields[2].clone());
+    }
+    Some(payment.to_uri())
+}
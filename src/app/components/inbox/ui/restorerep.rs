@@ -0,0 +1,100 @@
+use super::primary::show_inbox;
+use crate::app::coin::Colour;
+use crate::app::components::work::{
+    precompute::{cancel_precompute, precompute_work},
+    progress::work_label,
+};
+use crate::app::constants::colours::RED;
+use crate::app::userdata::UserData;
+use cursive::traits::Resizable;
+use cursive::utils::markup::StyledString;
+use cursive::views::{Dialog, ProgressBar};
+use cursive::Cursive;
+use dagchat_core::crypto::pow::WorkControl;
+use dagchat_core::messages::representative::GarbageRep;
+use dagchat_core::rpc::{accountinfo::get_account_info, changerep::change_rep};
+use dagchat_core::Error;
+
+pub fn show_restore_rep(s: &mut Cursive, garbage: GarbageRep) {
+    let data = &s.user_data::<UserData>().unwrap();
+    let coin = &data.coins[data.coin_idx];
+    let default_rep = coin.network.default_rep.clone();
+    let mut content = format!(
+        "\nThis account's representative\n{}\nlooks like part of a message rather than a real representative. This happens when sending a message is interrupted.\n\nYou can change it back to your default representative\n{}",
+        garbage.representative, default_rep
+    );
+    if let Some(last_legit) = &garbage.last_legit {
+        content.push_str(&format!(
+            "\n\nor to the last legitimate representative this account had\n{}",
+            last_legit
+        ));
+    }
+    let mut dialog = Dialog::text(StyledString::styled(content, coin.colour())).button(
+        "Use default",
+        move |s| {
+            s.pop_layer();
+            restore_rep(s, default_rep.clone());
+        },
+    );
+    if let Some(last_legit) = garbage.last_legit {
+        dialog = dialog.button("Use last", move |s| {
+            s.pop_layer();
+            restore_rep(s, last_legit.clone());
+        });
+    }
+    s.add_layer(
+        dialog
+            .dismiss_button("Ignore")
+            .title("Representative looks wrong")
+            .max_width(80),
+    );
+}
+
+fn restore_rep(s: &mut Cursive, rep_address: String) {
+    cancel_precompute(s);
+    let ticks = 1000;
+    let cb = s.cb_sink().clone();
+    let data = &s.user_data::<UserData>().unwrap();
+    let wallet = &data.wallets[data.wallet_idx];
+    let account = &wallet.accounts[wallet.acc_idx];
+    let private_key = account.private_key;
+    let address = account.address.clone();
+    let coin = data.coins[data.coin_idx].clone();
+    let control = WorkControl::default();
+    let cancel = control.clone();
+    s.add_layer(
+        Dialog::around(
+            ProgressBar::new()
+                .range(0, ticks)
+                .with_label(work_label(control.clone()))
+                .with_task(move |counter| {
+                    let result = get_account_info(&address, &coin.network.node_url).and_then(
+                        |account_info| {
+                            counter.tick(200);
+                            change_rep(&private_key, account_info, &rep_address, &coin, &control)
+                        },
+                    );
+                    cb.send(Box::new(move |s| {
+                        s.set_autorefresh(false);
+                        s.pop_layer();
+                        match result {
+                            Ok(_) => {
+                                show_inbox(s);
+                                precompute_work(s, vec![address]);
+                                s.add_layer(Dialog::info("Successfully changed representative!"));
+                            }
+                            Err(Error::Cancelled) => {}
+                            Err(e) => s.add_layer(Dialog::info(StyledString::styled(
+                                format!("Failed to change representative. Error: {}", e),
+                                RED,
+                            ))),
+                        }
+                    }))
+                    .unwrap();
+                })
+                .full_width(),
+        )
+        .button("Cancel", move |_| cancel.cancel()),
+    );
+    s.set_autorefresh(true);
+}
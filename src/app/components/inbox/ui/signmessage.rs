@@ -1,5 +1,6 @@
+use crate::app::coin::Colour;
 use crate::app::constants::colours::RED;
-use crate::crypto::signmessage::sign_message;
+use dagchat_core::crypto::signmessage::sign_message;
 use crate::app::{
     clipboard::paste_clip,
     clipboard::copy_to_clip,
@@ -29,7 +30,7 @@ pub fn show_sign_message(s: &mut Cursive) {
     let account = &wallet.accounts[wallet.acc_idx];
     let private_key = account.private_key;
     let coin = data.coins[data.coin_idx].clone();
-    let sub_title_colour = get_subtitle_colour(coin.colour);
+    let sub_title_colour = get_subtitle_colour(coin.colour());
     s.add_layer(
         HideableView::new(
         Dialog::around(
@@ -73,7 +74,6 @@ pub fn show_sign_message(s: &mut Cursive) {
                         s.add_layer(
                             Dialog::info(StyledString::styled("Failed to sign message", RED)),
                         );
-                        return;
                     }
                 }))
                 .child(Button::new("Back", show_inbox))),
@@ -0,0 +1,92 @@
+use crate::app::coin::Colour;
+use crate::app::constants::colours::RED;
+use crate::app::{
+    clipboard::paste_clip, helpers::go_back, themes::get_subtitle_colour, userdata::UserData,
+};
+use cursive::traits::{Nameable, Resizable};
+use cursive::utils::markup::StyledString;
+use cursive::views::{Button, Dialog, DummyView, EditView, LinearLayout, TextArea, TextView};
+use cursive::Cursive;
+use dagchat_core::crypto::signmessage::verify_message;
+
+// The address is filled in when verifying a contact's signature.
+pub fn show_verify_message(s: &mut Cursive, address: String) {
+    let data = &s.user_data::<UserData>().unwrap();
+    let sub_title_colour = get_subtitle_colour(data.coins[data.coin_idx].colour());
+    s.add_layer(
+        Dialog::around(
+            LinearLayout::vertical()
+                .child(DummyView)
+                .child(TextView::new(StyledString::styled(
+                    "Address",
+                    sub_title_colour,
+                )))
+                .child(EditView::new().content(address).with_name("address"))
+                .child(DummyView)
+                .child(TextView::new(StyledString::styled(
+                    "Message",
+                    sub_title_colour,
+                )))
+                .child(TextArea::new().with_name("message"))
+                .child(Button::new("Paste", |s| {
+                    let content = paste_clip(s);
+                    s.call_on_name("message", |view: &mut TextArea| view.set_content(content));
+                }))
+                .child(DummyView)
+                .child(TextView::new(StyledString::styled(
+                    "Signature",
+                    sub_title_colour,
+                )))
+                .child(EditView::new().with_name("signature"))
+                .child(Button::new("Paste", |s| {
+                    let content = paste_clip(s);
+                    s.call_on_name("signature", |view: &mut EditView| view.set_content(content));
+                })),
+        )
+        .button("Verify", check_signature)
+        .button("Back", go_back)
+        .title("Verify message")
+        .max_width(80),
+    );
+}
+
+fn check_signature(s: &mut Cursive) {
+    let address = s
+        .call_on_name("address", |view: &mut EditView| view.get_content())
+        .unwrap();
+    let signature = s
+        .call_on_name("signature", |view: &mut EditView| view.get_content())
+        .unwrap();
+    //remove carriage returns, as when signing
+    let message = s
+        .call_on_name("message", |view: &mut TextArea| {
+            String::from(view.get_content()).replace("\r\n", "\n")
+        })
+        .unwrap();
+    if message.is_empty() {
+        s.add_layer(Dialog::info(
+            "You must provide the message that was signed!",
+        ));
+        return;
+    }
+    let data = &s.user_data::<UserData>().unwrap();
+    let coin = &data.coins[data.coin_idx];
+    let colour = coin.colour();
+    match verify_message(address.trim(), &message, signature.trim(), coin) {
+        Ok(true) => s.add_layer(Dialog::info(StyledString::styled(
+            format!(
+                "The signature is valid: the message was signed by\n{}",
+                address.trim()
+            ),
+            colour,
+        ))),
+        Ok(false) => s.add_layer(Dialog::info(StyledString::styled(
+            "The signature is not valid for this address and message.",
+            RED,
+        ))),
+        Err(e) => s.add_layer(Dialog::info(StyledString::styled(
+            format!("Failed to verify the message. Error: {}", e),
+            RED,
+        ))),
+    }
+}
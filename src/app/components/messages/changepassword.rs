@@ -1,7 +1,8 @@
+use super::journal::{load_journals, save_journals};
 use crate::app::constants::paths;
 use crate::app::userdata::UserData;
-use crate::crypto::aes::{decrypt_bytes, encrypt_bytes};
 use cursive::Cursive;
+use dagchat_core::crypto::aes::{decrypt_bytes, encrypt_bytes};
 use std::fs;
 
 pub fn change_messages_password(s: &mut Cursive, new_password: &str) -> Result<(), String> {
@@ -24,7 +25,8 @@ pub fn change_messages_password(s: &mut Cursive, new_password: &str) -> Result<(
             if encrypted_bytes.is_empty() {
                 continue;
             }
-            let decrypted_bytes = decrypt_bytes(&encrypted_bytes, &data.password)?;
+            let decrypted_bytes =
+                decrypt_bytes(&encrypted_bytes, &data.password).map_err(|e| e.to_string())?;
             let reencrypted_bytes = encrypt_bytes(&decrypted_bytes, new_password);
             let write_res = fs::write(&messages_file, reencrypted_bytes);
             if write_res.is_err() {
@@ -36,6 +38,8 @@ pub fn change_messages_password(s: &mut Cursive, new_password: &str) -> Result<(
             }
         }
     }
+    let journals = load_journals(&data.password)?;
+    save_journals(&journals, new_password)?;
     //eprintln!(
     //    "Saved and changed all new messages with password: {}",
     //    new_password
@@ -0,0 +1,136 @@
+use crate::app::constants::paths;
+use dagchat_core::coin::Coin;
+use dagchat_core::crypto::aes::{decrypt_bytes, encrypt_bytes};
+use dagchat_core::crypto::pow::WorkControl;
+use dagchat_core::messages::{
+    envelope::Content,
+    journal::Journal,
+    send::{build_message, send_hashes, MessageOptions},
+};
+use dagchat_core::rpc::process::publish_chain;
+use dagchat_core::Error;
+use std::fs;
+use std::path::PathBuf;
+
+fn journal_file() -> PathBuf {
+    let data_dir = dirs::data_dir().unwrap();
+    data_dir
+        .join(paths::DATA_DIR)
+        .join(paths::MESSAGES_DIR)
+        .join(paths::JOURNAL)
+}
+
+pub fn load_journals(password: &str) -> Result<Vec<Journal>, String> {
+    let journal_file = journal_file();
+    if !journal_file.exists() {
+        return Ok(vec![]);
+    }
+    let encrypted_bytes = fs::read(&journal_file).map_err(|e| {
+        format!(
+            "Failed to read journal file at path: {:?}\nError: {}",
+            journal_file, e
+        )
+    })?;
+    let bytes = decrypt_bytes(&encrypted_bytes, password).map_err(|_| {
+        format!(
+            "Failed to decrypt journal from file at path: {:?}",
+            journal_file
+        )
+    })?;
+    serde_json::from_slice(&bytes).map_err(|_| {
+        format!(
+            "Failed to deserialize journal from file at path: {:?}",
+            journal_file
+        )
+    })
+}
+
+pub fn save_journals(journals: &[Journal], password: &str) -> Result<(), String> {
+    let journal_file = journal_file();
+    if journals.is_empty() {
+        if journal_file.exists() {
+            fs::remove_file(&journal_file).map_err(|e| {
+                format!(
+                    "Failed to remove journal file at path: {:?}\nError: {}",
+                    journal_file, e
+                )
+            })?;
+        }
+        return Ok(());
+    }
+    // JSON rather than bincode, as blocks leave out empty work.
+    let bytes = serde_json::to_vec(journals).unwrap();
+    let encrypted_bytes = encrypt_bytes(&bytes, password);
+    fs::write(&journal_file, encrypted_bytes).map_err(|e| {
+        format!(
+            "Failed to write to journal file at path: {:?}\nError: {}",
+            journal_file, e
+        )
+    })
+}
+
+// Records the progress of a send, replacing any earlier entry for the
+// same account. Finished sends are dropped from the journal.
+pub fn record_journal(journal: &Journal, password: &str) -> Result<(), String> {
+    let mut journals = load_journals(password)?;
+    journals.retain(|j| j.account != journal.account);
+    if !journal.is_complete() {
+        journals.push(journal.clone());
+    }
+    save_journals(&journals, password)
+}
+
+pub fn remove_journal(account: &str, password: &str) -> Result<(), String> {
+    let mut journals = load_journals(password)?;
+    journals.retain(|j| j.account != account);
+    save_journals(&journals, password)
+}
+
+// Sends a message like send_message, but keeps a journal of the send
+// until every block is on chain so that an interrupted send can be
+// resumed or undone on the next unlock.
+// progress is ticked up to a total of 900 over the course of the send,
+// and on_publish is called once all work is done and publishing starts.
+// Returns the hash of the send to each recipient, in order.
+#[allow(clippy::too_many_arguments)]
+pub fn send_journaled_message(
+    private_key_bytes: &[u8; 32],
+    recipients: &[String],
+    raw: u128,
+    content: &Content,
+    options: &MessageOptions,
+    coin: &Coin,
+    password: &str,
+    progress: &dyn Fn(usize),
+    control: &WorkControl,
+    on_publish: &dyn Fn(),
+) -> dagchat_core::Result<Vec<String>> {
+    let blocks = build_message(
+        private_key_bytes,
+        recipients,
+        raw,
+        content,
+        options,
+        coin,
+        progress,
+        control,
+    )?;
+    let journal = Journal::new(&blocks, &recipients.join(", "), coin);
+    // Nothing is published unless the send can be picked up again.
+    record_journal(&journal, password).map_err(Error::Io)?;
+    on_publish();
+    let x = 200usize / blocks.len();
+    publish_chain(&blocks, 0, coin, &|confirmed| {
+        progress(x);
+        // The node has the block either way; a failed write is caught
+        // up on by syncing with the frontier when resuming.
+        let _ = record_journal(
+            &Journal {
+                confirmed,
+                ..journal.clone()
+            },
+            password,
+        );
+    })?;
+    Ok(send_hashes(&blocks))
+}
@@ -1,8 +1,11 @@
-use super::{save::create_key, structs::SavedMessage};
+use super::{
+    save::create_key,
+    structs::{LegacySavedMessage, SavedMessage, MESSAGES_MAGIC},
+};
 use crate::app::constants::paths;
 use crate::app::userdata::UserData;
-use crate::crypto::aes::decrypt_bytes;
 use cursive::Cursive;
+use dagchat_core::crypto::aes::decrypt_bytes;
 use std::fs;
 
 pub fn load_messages(s: &mut Cursive) -> Result<Vec<SavedMessage>, String> {
@@ -41,9 +44,14 @@ pub fn load_messages(s: &mut Cursive) -> Result<Vec<SavedMessage>, String> {
         let bytes = decrypt_bytes(&encrypted_bytes, &data.password);
 
         if let Ok(bytes) = bytes {
-            let messages_opt = bincode::deserialize(&bytes[..]);
-            if messages_opt.is_ok() {
-                messages = messages_opt.unwrap();
+            let messages_opt = match bytes.strip_prefix(MESSAGES_MAGIC) {
+                Some(bytes) => bincode::deserialize(bytes).ok(),
+                None => bincode::deserialize::<Vec<LegacySavedMessage>>(&bytes[..])
+                    .ok()
+                    .map(|legacy| legacy.into_iter().map(SavedMessage::from).collect()),
+            };
+            if let Some(messages_opt) = messages_opt {
+                messages = messages_opt;
             } else {
                 let error = format!(
                     "Failed to deserialize messages from file at path: {:?}",
@@ -1,6 +1,6 @@
 pub mod changepassword;
+pub mod journal;
 pub mod load;
-pub mod readmessage;
 pub mod save;
 pub mod structs;
 pub mod ui;
@@ -1,8 +1,9 @@
+use super::structs::MESSAGES_MAGIC;
 use crate::app::components::storage::save::save_to_storage;
 use crate::app::constants::paths;
 use crate::app::userdata::UserData;
-use crate::crypto::aes::encrypt_bytes;
 use cursive::Cursive;
+use dagchat_core::crypto::aes::encrypt_bytes;
 use rand::RngCore;
 use std::fs;
 
@@ -32,8 +33,10 @@ pub fn save_messages(s: &mut Cursive) -> Result<(), String> {
     let data = &s.user_data::<UserData>().unwrap();
     let wallet = &data.wallets[data.wallet_idx];
     let messages_file = messages_dir.join(format!("{}.dagchat", lookup_key));
-    let messages_bytes =
-        bincode::serialize(wallet.accounts[wallet.acc_idx].messages.as_ref().unwrap()).unwrap();
+    let mut messages_bytes = MESSAGES_MAGIC.to_vec();
+    messages_bytes.extend(
+        bincode::serialize(wallet.accounts[wallet.acc_idx].messages.as_ref().unwrap()).unwrap(),
+    );
     let encrypted_bytes = encrypt_bytes(&messages_bytes, &data.password);
     let write_res = fs::write(&messages_file, encrypted_bytes);
     if write_res.is_err() {
@@ -1,13 +1,9 @@
-use crate::rpc::blockinfo::BlockResponse;
+use dagchat_core::messages::envelope::Attachment;
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, Debug)]
-pub struct Message {
-    pub head: BlockResponse,
-    pub root_hash: String,
-    pub blocks: u64,
-    pub plaintext: String,
-}
+// Starts messages files written since attachments were added. Older
+// files start with the number of messages instead, which is never this.
+pub const MESSAGES_MAGIC: &[u8; 8] = b"dagchat\x02";
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct SavedMessage {
@@ -18,6 +14,32 @@ pub struct SavedMessage {
     pub amount: String,
     pub plaintext: String,
     pub hash: String,
+    pub attachment: Option<Attachment>,
+}
+
+// The layout messages were stored in before attachments.
+#[derive(Deserialize)]
+pub struct LegacySavedMessage {
+    pub outgoing: bool,
+    pub address: String,
+    pub timestamp: u64,
+    pub amount: String,
+    pub plaintext: String,
+    pub hash: String,
+}
+
+impl From<LegacySavedMessage> for SavedMessage {
+    fn from(legacy: LegacySavedMessage) -> SavedMessage {
+        SavedMessage {
+            outgoing: legacy.outgoing,
+            address: legacy.address,
+            timestamp: legacy.timestamp,
+            amount: legacy.amount,
+            plaintext: legacy.plaintext,
+            hash: legacy.hash,
+            attachment: None,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
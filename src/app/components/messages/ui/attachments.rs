@@ -0,0 +1,101 @@
+use crate::app::coin::Colour;
+use crate::app::components::offline::ui::primary::default_path;
+use crate::app::constants::colours::RED;
+use crate::app::{helpers::go_back, userdata::UserData};
+use cursive::traits::{Nameable, Resizable, Scrollable};
+use cursive::utils::markup::StyledString;
+use cursive::views::{Dialog, DummyView, LinearLayout, SelectView, TextArea, TextView};
+use cursive::Cursive;
+use dagchat_core::messages::envelope::Attachment;
+use std::fs;
+
+pub fn attachment_label(attachment: &Attachment) -> String {
+    format!(
+        "{} ({}, {} bytes)",
+        attachment.filename,
+        attachment.mime_type,
+        attachment.data.len()
+    )
+}
+
+pub fn show_attachments(s: &mut Cursive) {
+    let data = &s.user_data::<UserData>().unwrap();
+    let wallet = &data.wallets[data.wallet_idx];
+    let mut select = SelectView::<usize>::new();
+    if let Ok(messages) = &wallet.accounts[wallet.acc_idx].messages {
+        for (idx, message) in messages.iter().enumerate().rev() {
+            if let Some(attachment) = &message.attachment {
+                let direction = if message.outgoing { "To" } else { "From" };
+                let label = format!(
+                    "{} {}: {}",
+                    direction,
+                    message.address,
+                    attachment_label(attachment)
+                );
+                select.add_item(label, idx);
+            }
+        }
+    }
+    if select.is_empty() {
+        s.add_layer(Dialog::info(
+            "None of your messages on this account have attachments.",
+        ));
+        return;
+    }
+    select.set_on_submit(|s, idx| show_save_attachment(s, *idx));
+    s.add_layer(
+        Dialog::around(select.scrollable().max_width(100).max_height(10))
+            .button("Back", go_back)
+            .title("Attachments"),
+    );
+}
+
+fn show_save_attachment(s: &mut Cursive, idx: usize) {
+    let data = &s.user_data::<UserData>().unwrap();
+    let wallet = &data.wallets[data.wallet_idx];
+    let messages = wallet.accounts[wallet.acc_idx].messages.as_ref().unwrap();
+    let attachment = messages[idx].attachment.clone().unwrap();
+    let colour = data.coins[data.coin_idx].colour();
+    // Only the file's name is used, so a sender can't choose where it's
+    // saved.
+    let filename = std::path::Path::new(&attachment.filename)
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| String::from("attachment"));
+    s.add_layer(
+        Dialog::around(
+            LinearLayout::vertical()
+                .child(TextView::new(StyledString::styled(
+                    attachment_label(&attachment),
+                    colour,
+                )))
+                .child(DummyView)
+                .child(TextView::new(StyledString::styled("Save to", colour)))
+                .child(
+                    TextArea::new()
+                        .content(default_path(&filename))
+                        .with_name("path")
+                        .max_width(80),
+                ),
+        )
+        .button("Save", move |s| {
+            let path = s
+                .call_on_name("path", |view: &mut TextArea| {
+                    String::from(view.get_content().trim())
+                })
+                .unwrap();
+            match fs::write(&path, &attachment.data) {
+                Ok(_) => {
+                    s.pop_layer();
+                    s.add_layer(Dialog::info(format!("Saved the attachment to {}", path)));
+                }
+                Err(e) => s.add_layer(Dialog::info(StyledString::styled(
+                    format!("Failed to save the attachment. Error: {}", e),
+                    RED,
+                ))),
+            }
+        })
+        .button("Back", go_back)
+        .title("Save attachment"),
+    );
+}
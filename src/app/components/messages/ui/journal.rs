@@ -0,0 +1,175 @@
+use super::super::journal::{load_journals, record_journal, remove_journal};
+use crate::app::coin::Colour;
+use crate::app::components::accounts::structs::Account;
+use crate::app::components::work::{precompute::cancel_precompute, progress::work_label};
+use crate::app::constants::colours::RED;
+use crate::app::userdata::UserData;
+use cursive::traits::Resizable;
+use cursive::utils::markup::StyledString;
+use cursive::views::{Dialog, ProgressBar};
+use cursive::Cursive;
+use dagchat_core::crypto::pow::WorkControl;
+use dagchat_core::messages::journal::Journal;
+use dagchat_core::Error;
+
+// Offers to finish or undo any message sends that were interrupted,
+// one at a time.
+pub fn check_journals(s: &mut Cursive) {
+    let data = &s.user_data::<UserData>().unwrap();
+    match load_journals(&data.password) {
+        Ok(mut journals) => {
+            if !journals.is_empty() {
+                show_journal(s, journals.remove(0));
+            }
+        }
+        Err(e) => s.add_layer(Dialog::info(StyledString::styled(e, RED))),
+    }
+}
+
+// A new message from an account would take its representative from a
+// send that hasn't finished, so that one has to be dealt with first.
+// Returns whether the current account has such a send.
+pub fn check_interrupted_send(s: &mut Cursive) -> bool {
+    let data = &s.user_data::<UserData>().unwrap();
+    let wallet = &data.wallets[data.wallet_idx];
+    let address = wallet.accounts[wallet.acc_idx].address.clone();
+    let journal = match load_journals(&data.password) {
+        Ok(journals) => journals.into_iter().find(|j| j.account == address),
+        Err(e) => {
+            s.add_layer(Dialog::info(StyledString::styled(e, RED)));
+            return true;
+        }
+    };
+    match journal {
+        Some(journal) => {
+            show_journal(s, journal);
+            s.add_layer(Dialog::info(
+                "An earlier message from this account was interrupted. Resume it or restore your representative before sending another.",
+            ));
+            true
+        }
+        None => false,
+    }
+}
+
+fn show_journal(s: &mut Cursive, journal: Journal) {
+    let data = &s.user_data::<UserData>().unwrap();
+    let coin_idx = data
+        .coins
+        .iter()
+        .position(|coin| journal.account.starts_with(&coin.prefix));
+    let coin_idx = match coin_idx {
+        Some(coin_idx) => coin_idx,
+        None => return,
+    };
+    let colour = data.coins[coin_idx].colour();
+    let content = format!(
+        "\nA message send from\n{}\nto\n{}\nwas interrupted with {} of its {} blocks published.\n\nYou can resume the send, or abandon it and restore your account's original representative.",
+        journal.account,
+        journal.recipient,
+        journal.confirmed,
+        journal.blocks.len()
+    );
+    let resume_journal = journal.clone();
+    s.add_layer(
+        Dialog::text(StyledString::styled(content, colour))
+            .dismiss_button("Later")
+            .button("Resume", move |s| {
+                s.pop_layer();
+                process_journal(s, resume_journal.clone(), coin_idx, true);
+            })
+            .button("Restore rep", move |s| {
+                s.pop_layer();
+                process_journal(s, journal.clone(), coin_idx, false);
+            })
+            .title("Interrupted message")
+            .max_width(80),
+    );
+}
+
+fn process_journal(s: &mut Cursive, mut journal: Journal, coin_idx: usize, resume: bool) {
+    cancel_precompute(s);
+    let ticks = 1000;
+    let cb = s.cb_sink().clone();
+    let data = &s.user_data::<UserData>().unwrap();
+    let coin = data.coins[coin_idx].clone();
+    let password = data.password.clone();
+    let mut private_key: Option<[u8; 32]> = None;
+    for wallet in &data.wallets {
+        for index in &wallet.indexes {
+            let account = Account::with_index(wallet, *index, &coin.prefix);
+            if account.address == journal.account {
+                private_key = Some(account.private_key);
+            }
+        }
+    }
+    let private_key = match private_key {
+        Some(private_key) => private_key,
+        None if !resume => {
+            s.add_layer(Dialog::info(StyledString::styled(
+                format!(
+                    "{} isn't in any of your wallets, so its representative can't be restored.",
+                    journal.account
+                ),
+                RED,
+            )));
+            return;
+        }
+        // Resuming only publishes blocks that are already signed.
+        None => [0u8; 32],
+    };
+    let control = WorkControl::default();
+    let cancel = control.clone();
+    let dialog = Dialog::around(
+        ProgressBar::new()
+            .range(0, ticks)
+            .with_label(work_label(control.clone()))
+            .with_task(move |counter| {
+                let result = if resume {
+                    let x = ticks / journal.blocks.len().max(1);
+                    journal.resume(&coin, &|j| {
+                        counter.tick(x);
+                        let _ = record_journal(j, &password);
+                    })
+                } else {
+                    journal.restore(&private_key, &coin, &control).map(|_| ())
+                };
+                let saved = match &result {
+                    Ok(()) => remove_journal(&journal.account, &password),
+                    Err(Error::Cancelled) => Ok(()),
+                    Err(_) => record_journal(&journal, &password),
+                };
+                cb.send(Box::new(move |s| {
+                    s.set_autorefresh(false);
+                    s.pop_layer();
+                    match result {
+                        Ok(()) => {
+                            if let Err(e) = saved {
+                                s.add_layer(Dialog::info(StyledString::styled(e, RED)));
+                            }
+                            check_journals(s);
+                            s.add_layer(Dialog::info(if resume {
+                                "Message send completed successfully!"
+                            } else {
+                                "Restored representative successfully!"
+                            }));
+                        }
+                        Err(Error::Cancelled) => check_journals(s),
+                        Err(e) => s.add_layer(Dialog::info(StyledString::styled(
+                            format!("Failed to recover the message send. Error: {}", e),
+                            RED,
+                        ))),
+                    }
+                }))
+                .unwrap();
+            })
+            .full_width(),
+    );
+    // Only restoring generates work; resuming just publishes.
+    if resume {
+        s.add_layer(dialog);
+    } else {
+        s.add_layer(dialog.button("Cancel", move |_| cancel.cancel()));
+    }
+    s.set_autorefresh(true);
+}
@@ -1,3 +1,6 @@
+pub mod attachments;
 pub mod filter;
+pub mod journal;
 pub mod primary;
+pub mod recover;
 pub mod search;
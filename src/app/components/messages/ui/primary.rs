@@ -1,5 +1,11 @@
 use super::super::structs::Filter;
-use super::{filter::show_filter, search::show_search};
+use super::{
+    attachments::{attachment_label, show_attachments},
+    filter::show_filter,
+    recover::show_recover,
+    search::show_search,
+};
+use crate::app::coin::Colour;
 use crate::app::{constants::colours::OFF_WHITE, helpers::go_back, userdata::UserData};
 use chrono::{DateTime, Local, NaiveDateTime, Utc};
 use cursive::traits::{Resizable, Scrollable};
@@ -16,16 +22,22 @@ pub fn show_messages(s: &mut Cursive, mut filter: Filter) {
         s.add_layer(Dialog::info(err_msg));
         return;
     } else if messages.as_ref().unwrap().is_empty() {
-        s.add_layer(Dialog::info(
-            "You haven't sent or received any messages yet with dagchat on this account!",
-        ));
+        s.add_layer(
+            Dialog::text("You haven't sent or received any messages yet with dagchat on this account! If you have on another device, they can be recovered from the chain.")
+                .button("Recover from chain", |s| {
+                    s.pop_layer();
+                    show_recover(s);
+                })
+                .button("Back", go_back)
+                .max_width(60),
+        );
         return;
     }
 
     let mut output = StyledString::new();
     let mut search_term = String::from("");
-    if filter.search_term.is_some() {
-        search_term = filter.search_term.unwrap();
+    if let Some(term) = filter.search_term {
+        search_term = term;
     };
 
     for message in messages.as_ref().unwrap().iter().rev() {
@@ -52,7 +64,7 @@ pub fn show_messages(s: &mut Cursive, mut filter: Filter) {
             a = "Received";
             b = "From:";
         }
-        let colour = data.coins[data.coin_idx].colour;
+        let colour = data.coins[data.coin_idx].colour();
         let mut message_info = StyledString::styled(format!("{} at: ", a), OFF_WHITE);
         message_info.append(StyledString::styled(timestamp_str, colour));
         message_info.append(StyledString::styled(format!("\n{} ", b), OFF_WHITE));
@@ -66,6 +78,10 @@ pub fn show_messages(s: &mut Cursive, mut filter: Filter) {
         };
 
         message_info.append(StyledString::styled(source, colour));
+        if let Some(attachment) = &message.attachment {
+            message_info.append(StyledString::styled("\nAttachment: ", OFF_WHITE));
+            message_info.append(StyledString::styled(attachment_label(attachment), colour));
+        }
         if !message.plaintext.is_empty() {
             message_info.append(StyledString::styled("\nMessage: ", OFF_WHITE));
             if message.amount != "1 RAW" {
@@ -89,7 +105,7 @@ pub fn show_messages(s: &mut Cursive, mut filter: Filter) {
             ));
         }
 
-        if !search_term.as_str().is_empty() {
+        if !search_term.is_empty() {
             if message_info.source().contains(&search_term) {
                 output.append(message_info);
             }
@@ -120,12 +136,19 @@ pub fn show_messages(s: &mut Cursive, mut filter: Filter) {
                     show_filter(s, filter_copy.clone())
                 }))
                 .child(DummyView)
+                .child(Button::new("Attachments", show_attachments))
+                .child(DummyView)
+                .child(Button::new("Recover", |s| {
+                    s.pop_layer();
+                    show_recover(s);
+                }))
+                .child(DummyView)
                 .child(Button::new("Back", go_back)),
         )
         .child(DummyView);
-    if filter.search_term.is_some() {
+    if let Some(search_term) = filter.search_term {
         content.add_child(TextView::new(StyledString::styled(
-            format!("Contains: {}", filter.search_term.unwrap()),
+            format!("Contains: {}", search_term),
             OFF_WHITE,
         )))
     }
@@ -133,7 +156,7 @@ pub fn show_messages(s: &mut Cursive, mut filter: Filter) {
         content.add_child(DummyView);
         content.add_child(TextView::new(StyledString::styled(
             "No messages found.",
-            data.coins[data.coin_idx].colour,
+            data.coins[data.coin_idx].colour(),
         )));
     }
     content.add_child(
@@ -0,0 +1,110 @@
+use super::super::{save::save_messages, structs::Filter, structs::SavedMessage};
+use super::primary::show_messages;
+use crate::app::constants::{colours::RED, SHOW_TO_DP};
+use crate::app::userdata::UserData;
+use cursive::traits::Resizable;
+use cursive::utils::markup::StyledString;
+use cursive::views::{Dialog, ProgressBar};
+use cursive::Cursive;
+use dagchat_core::crypto::conversions::display_to_dp;
+use dagchat_core::crypto::pow::WorkControl;
+use dagchat_core::messages::recover::recover_messages;
+use dagchat_core::Error;
+use std::collections::HashSet;
+
+// Rebuilds the message log from the account's chain, adding any
+// messages that aren't already saved.
+pub fn show_recover(s: &mut Cursive) {
+    let ticks = 1000;
+    let cb = s.cb_sink().clone();
+    let data = &s.user_data::<UserData>().unwrap();
+    let wallet = &data.wallets[data.wallet_idx];
+    let account = &wallet.accounts[wallet.acc_idx];
+    let known: HashSet<String> = match &account.messages {
+        Ok(messages) => messages.iter().map(|m| m.hash.to_uppercase()).collect(),
+        Err(e) => {
+            let e = e.clone();
+            s.add_layer(Dialog::info(e));
+            return;
+        }
+    };
+    let private_key = account.private_key;
+    let coin = data.coins[data.coin_idx].clone();
+    let control = WorkControl::default();
+    let cancel = control.clone();
+    s.add_layer(
+        Dialog::around(
+            ProgressBar::new()
+                .range(0, ticks)
+                .with_task(move |counter| {
+                    let result = recover_messages(
+                        &private_key,
+                        &coin,
+                        &known,
+                        &|n| counter.tick(n),
+                        &control,
+                    );
+                    cb.send(Box::new(move |s| {
+                        s.set_autorefresh(false);
+                        s.pop_layer();
+                        let recovered = match result {
+                            Ok(recovered) => recovered,
+                            Err(Error::Cancelled) => return,
+                            Err(e) => {
+                                s.add_layer(Dialog::info(StyledString::styled(
+                                    format!("Failed to recover messages. Error: {}", e),
+                                    RED,
+                                )));
+                                return;
+                            }
+                        };
+                        let count = recovered.len();
+                        let data = &mut s.user_data::<UserData>().unwrap();
+                        let wallet = &mut data.wallets[data.wallet_idx];
+                        let messages = wallet.accounts[wallet.acc_idx].messages.as_mut().unwrap();
+                        for message in recovered {
+                            messages.push(SavedMessage {
+                                outgoing: message.outgoing,
+                                address: message.address,
+                                timestamp: message.timestamp,
+                                amount: display_to_dp(
+                                    message.amount,
+                                    SHOW_TO_DP,
+                                    &coin.multiplier,
+                                    &coin.ticker,
+                                ),
+                                plaintext: message.content.text,
+                                hash: message.hash,
+                                attachment: message.content.attachment,
+                            });
+                        }
+                        messages.sort_by_key(|message| message.timestamp);
+                        if count == 0 {
+                            s.add_layer(Dialog::info(
+                                "No messages were found on chain that aren't already saved.",
+                            ));
+                            return;
+                        }
+                        if let Err(e) = save_messages(s) {
+                            s.add_layer(
+                                Dialog::info(StyledString::styled(e, RED))
+                                    .title("Failed to save messages"),
+                            );
+                            return;
+                        }
+                        show_messages(s, Filter::default());
+                        s.add_layer(Dialog::info(format!(
+                            "Recovered {} message{} from the chain.",
+                            count,
+                            if count == 1 { "" } else { "s" }
+                        )));
+                    }))
+                    .unwrap();
+                })
+                .full_width(),
+        )
+        .title("Recovering messages")
+        .button("Cancel", move |_| cancel.cancel()),
+    );
+    s.set_autorefresh(true);
+}
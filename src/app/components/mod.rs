@@ -2,9 +2,11 @@ pub mod accounts;
 pub mod addressbook;
 pub mod inbox;
 pub mod messages;
+pub mod offline;
 pub mod receive;
 pub mod send;
 pub mod settings;
 pub mod storage;
 pub mod title;
 pub mod wallets;
+pub mod work;
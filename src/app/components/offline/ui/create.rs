@@ -0,0 +1,196 @@
+use super::primary::{default_path, get_content, UNSIGNED_FILE};
+use crate::app::clipboard::paste_clip;
+use crate::app::coin::Colour;
+use crate::app::constants::colours::RED;
+use crate::app::helpers::go_back;
+use crate::app::themes::get_subtitle_colour;
+use crate::app::userdata::UserData;
+use cursive::traits::{Nameable, Resizable};
+use cursive::utils::markup::StyledString;
+use cursive::views::{
+    Button, Dialog, DummyView, LinearLayout, ProgressBar, RadioGroup, TextArea, TextView, ViewRef,
+};
+use cursive::Cursive;
+use dagchat_core::crypto::{address::validate_address, conversions::whole_to_raw};
+use dagchat_core::offline::Transaction;
+
+struct Action;
+impl Action {
+    const SEND: usize = 0;
+    const MESSAGE: usize = 1;
+    const RECEIVE: usize = 2;
+    const CHANGE_REP: usize = 3;
+}
+
+pub fn show_create(s: &mut Cursive) {
+    let data = &s.user_data::<UserData>().unwrap();
+    let colour = get_subtitle_colour(data.coins[data.coin_idx].colour());
+    let ticker = data.coins[data.coin_idx].ticker.clone();
+
+    let mut action: RadioGroup<usize> = RadioGroup::new();
+    let actions = LinearLayout::horizontal()
+        .child(action.button(Action::SEND, "Send"))
+        .child(DummyView)
+        .child(action.button(Action::MESSAGE, "Message"))
+        .child(DummyView)
+        .child(action.button(Action::RECEIVE, "Receive all"))
+        .child(DummyView)
+        .child(action.button(Action::CHANGE_REP, "Change rep"));
+
+    let form = LinearLayout::vertical()
+        .child(TextView::new(StyledString::styled(
+            "Watch-only address",
+            colour,
+        )))
+        .child(TextArea::new().with_name("account").max_width(68))
+        .child(Button::new("Paste", |s| {
+            let mut account: ViewRef<TextArea> = s.find_name("account").unwrap();
+            account.set_content(paste_clip(s));
+        }))
+        .child(DummyView)
+        .child(actions)
+        .child(DummyView)
+        .child(TextView::new(StyledString::styled(
+            "Recipient or representative address",
+            colour,
+        )))
+        .child(TextArea::new().with_name("target").max_width(68))
+        .child(Button::new("Paste", |s| {
+            let mut target: ViewRef<TextArea> = s.find_name("target").unwrap();
+            target.set_content(paste_clip(s));
+        }))
+        .child(DummyView)
+        .child(TextView::new(StyledString::styled(
+            format!("Amount ({} is optional for messages)", ticker.trim()),
+            colour,
+        )))
+        .child(TextArea::new().with_name("amount"))
+        .child(DummyView)
+        .child(TextView::new(StyledString::styled("Message", colour)))
+        .child(TextArea::new().with_name("message").max_width(80))
+        .child(DummyView)
+        .child(TextView::new(StyledString::styled(
+            "Save unsigned transaction to",
+            colour,
+        )))
+        .child(
+            TextArea::new()
+                .content(default_path(UNSIGNED_FILE))
+                .with_name("path")
+                .max_width(80),
+        );
+
+    s.add_layer(
+        Dialog::around(form)
+            .button("Create", move |s| {
+                let choice = *action.selection();
+                create_transaction(s, choice);
+            })
+            .button("Back", go_back)
+            .title("Create unsigned transaction")
+            .padding_lrtb(1, 1, 1, 0),
+    );
+}
+
+fn create_transaction(s: &mut Cursive, action: usize) {
+    let account = get_content(s, "account");
+    let target = get_content(s, "target");
+    let amount = get_content(s, "amount");
+    let message = s
+        .call_on_name("message", |view: &mut TextArea| {
+            String::from(view.get_content())
+        })
+        .unwrap();
+    let path = get_content(s, "path");
+
+    let data = &s.user_data::<UserData>().unwrap();
+    let coin = data.coins[data.coin_idx].clone();
+    if !validate_address(&account) || !account.starts_with(&coin.prefix) {
+        s.add_layer(Dialog::info(format!(
+            "The watch-only address must be a valid {} address.",
+            coin.name
+        )));
+        return;
+    }
+    if action != Action::RECEIVE && !validate_address(&target) {
+        s.add_layer(Dialog::info(
+            "The recipient or representative address is invalid.",
+        ));
+        return;
+    }
+    if action == Action::MESSAGE && message.trim().is_empty() {
+        s.add_layer(Dialog::info(
+            "You must provide message content to send a message!",
+        ));
+        return;
+    }
+    let mut raw = 0;
+    if action == Action::MESSAGE {
+        raw = 1;
+    }
+    if (action == Action::SEND || action == Action::MESSAGE) && !amount.is_empty() {
+        match whole_to_raw(amount, &coin.multiplier) {
+            Some(amount) if amount > 0 => raw = amount,
+            _ => {
+                s.add_layer(Dialog::info("The amount was invalid."));
+                return;
+            }
+        }
+    }
+    if action == Action::SEND && raw == 0 {
+        s.add_layer(Dialog::info(format!(
+            "You must provide an amount of {} to send!",
+            coin.name
+        )));
+        return;
+    }
+
+    let ticks = 1000;
+    let cb = s.cb_sink().clone();
+    s.add_layer(
+        Dialog::around(
+            ProgressBar::new()
+                .range(0, ticks)
+                .with_task(move |counter| {
+                    let transaction = match action {
+                        Action::SEND => Transaction::send(&account, &target, raw, &coin),
+                        Action::MESSAGE => {
+                            Transaction::message(&account, &target, raw, message, &coin)
+                        }
+                        Action::RECEIVE => Transaction::receive_all(&account, &coin),
+                        _ => Transaction::change_rep(&account, &target, &coin),
+                    };
+                    counter.tick(800);
+                    let result = transaction
+                        .and_then(|transaction| transaction.write(&path).map(|_| transaction));
+                    cb.send(Box::new(move |s| {
+                        s.set_autorefresh(false);
+                        s.pop_layer();
+                        match result {
+                            Ok(transaction) => {
+                                s.pop_layer();
+                                s.add_layer(
+                                    Dialog::info(format!(
+                                        "{}\n\nSaved the unsigned transaction of {} blocks to\n{}\n\nMove it to your offline device to sign it.",
+                                        transaction.description,
+                                        transaction.blocks.len(),
+                                        path
+                                    ))
+                                    .title("Transaction created")
+                                    .max_width(80),
+                                );
+                            }
+                            Err(e) => s.add_layer(Dialog::info(StyledString::styled(
+                                format!("Failed to create the transaction. Error: {}", e),
+                                RED,
+                            ))),
+                        }
+                    }))
+                    .unwrap();
+                })
+                .full_width(),
+        )
+        .title("Creating transaction"),
+    );
+    s.set_autorefresh(true);
+}
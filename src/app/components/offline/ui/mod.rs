@@ -0,0 +1,4 @@
+mod create;
+pub mod primary;
+mod publish;
+mod sign;
@@ -0,0 +1,49 @@
+use super::create::show_create;
+use super::publish::show_publish;
+use super::sign::show_sign;
+use crate::app::coin::Colour;
+use crate::app::helpers::go_back;
+use crate::app::userdata::UserData;
+use cursive::traits::Resizable;
+use cursive::utils::markup::StyledString;
+use cursive::views::{Dialog, TextArea};
+use cursive::Cursive;
+
+pub const UNSIGNED_FILE: &str = "dagchat-unsigned.json";
+pub const SIGNED_FILE: &str = "dagchat-signed.json";
+
+pub fn show_offline(s: &mut Cursive) {
+    let data = &s.user_data::<UserData>().unwrap();
+    let coin = &data.coins[data.coin_idx];
+    let colour = coin.colour();
+    let content = format!(
+        "\nKeep a wallet's keys on a device that never goes online.\n\n1. Create an unsigned transaction on an online device, which only needs the account's address.\n2. Sign it on the offline device holding the wallet, optionally generating the work there too.\n3. Publish the signed transaction from the online device.\n\nTransactions are made for the currently selected coin: {}.",
+        coin.name
+    );
+    s.add_layer(
+        Dialog::text(StyledString::styled(content, colour))
+            .button("Create", show_create)
+            .button("Sign", show_sign)
+            .button("Publish", show_publish)
+            .button("Back", go_back)
+            .title("Offline signing")
+            .max_width(80),
+    );
+}
+
+// Transaction files default to the home directory, where they're easy
+// to find when moving them between devices.
+pub fn default_path(file_name: &str) -> String {
+    dirs::home_dir()
+        .unwrap_or_default()
+        .join(file_name)
+        .to_string_lossy()
+        .to_string()
+}
+
+pub fn get_content(s: &mut Cursive, name: &str) -> String {
+    s.call_on_name(name, |view: &mut TextArea| {
+        String::from(view.get_content().trim())
+    })
+    .unwrap()
+}
@@ -0,0 +1,134 @@
+use super::primary::{default_path, get_content, SIGNED_FILE};
+use crate::app::coin::Colour;
+use crate::app::components::work::{precompute::cancel_precompute, progress::work_label};
+use crate::app::constants::colours::RED;
+use crate::app::helpers::go_back;
+use crate::app::themes::get_subtitle_colour;
+use crate::app::userdata::UserData;
+use cursive::traits::{Nameable, Resizable};
+use cursive::utils::markup::StyledString;
+use cursive::views::{Dialog, DummyView, LinearLayout, ProgressBar, TextArea, TextView};
+use cursive::Cursive;
+use dagchat_core::crypto::pow::WorkControl;
+use dagchat_core::offline::Transaction;
+use dagchat_core::Error;
+
+pub fn show_publish(s: &mut Cursive) {
+    let data = &s.user_data::<UserData>().unwrap();
+    let colour = get_subtitle_colour(data.coins[data.coin_idx].colour());
+    let form = LinearLayout::vertical()
+        .child(TextView::new(StyledString::styled(
+            "Signed transaction",
+            colour,
+        )))
+        .child(
+            TextArea::new()
+                .content(default_path(SIGNED_FILE))
+                .with_name("path")
+                .max_width(80),
+        )
+        .child(DummyView);
+    s.add_layer(
+        Dialog::around(form)
+            .button("Publish", check_transaction)
+            .button("Back", go_back)
+            .title("Publish transaction")
+            .padding_lrtb(1, 1, 1, 0),
+    );
+}
+
+fn check_transaction(s: &mut Cursive) {
+    let path = get_content(s, "path");
+    let transaction = match Transaction::read(&path) {
+        Ok(transaction) => transaction,
+        Err(e) => {
+            s.add_layer(Dialog::info(StyledString::styled(
+                format!("Failed to read the transaction. Error: {}", e),
+                RED,
+            )));
+            return;
+        }
+    };
+    if !transaction.is_signed() {
+        s.add_layer(Dialog::info(
+            "This transaction hasn't been signed yet. Sign it on your offline device first.",
+        ));
+        return;
+    }
+    let data = &s.user_data::<UserData>().unwrap();
+    let coin_idx = match data
+        .coins
+        .iter()
+        .position(|coin| coin.name == transaction.coin)
+    {
+        Some(coin_idx) => coin_idx,
+        None => {
+            s.add_layer(Dialog::info(format!(
+                "Transactions for {} aren't supported.",
+                transaction.coin
+            )));
+            return;
+        }
+    };
+    let colour = data.coins[coin_idx].colour();
+    let content = format!(
+        "\n{}\n\nAccount\n{}\n\nBlocks: {}",
+        transaction.description,
+        transaction.account,
+        transaction.blocks.len()
+    );
+    s.add_layer(
+        Dialog::text(StyledString::styled(content, colour))
+            .button("Publish", move |s| {
+                s.pop_layer();
+                publish_transaction(s, transaction.clone(), coin_idx);
+            })
+            .button("Back", go_back)
+            .title("Confirm transaction")
+            .max_width(80),
+    );
+}
+
+fn publish_transaction(s: &mut Cursive, transaction: Transaction, coin_idx: usize) {
+    cancel_precompute(s);
+    let ticks = 1000;
+    let cb = s.cb_sink().clone();
+    let data = &s.user_data::<UserData>().unwrap();
+    let coin = data.coins[coin_idx].clone();
+    let control = WorkControl::default();
+    let cancel = control.clone();
+    s.add_layer(
+        Dialog::around(
+            ProgressBar::new()
+                .range(0, ticks)
+                .with_label(work_label(control.clone()))
+                .with_task(move |counter| {
+                    let result = transaction.publish(&coin, &|n| counter.tick(n), &control);
+                    cb.send(Box::new(move |s| {
+                        s.set_autorefresh(false);
+                        s.pop_layer();
+                        match result {
+                            Ok(()) => {
+                                s.pop_layer();
+                                s.add_layer(Dialog::info(
+                                    "Published the transaction successfully!",
+                                ));
+                            }
+                            Err(Error::Cancelled) => {
+                                s.add_layer(Dialog::info("Publishing cancelled."))
+                            }
+                            Err(e) => s.add_layer(Dialog::info(StyledString::styled(
+                                format!("Failed to publish the transaction. Error: {}", e),
+                                RED,
+                            ))),
+                        }
+                    }))
+                    .unwrap();
+                })
+                .full_width(),
+        )
+        .title("Publishing transaction")
+        .button("Cancel", move |_| cancel.cancel()),
+    );
+    s.set_autorefresh(true);
+}
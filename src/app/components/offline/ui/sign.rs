@@ -0,0 +1,213 @@
+use super::primary::{default_path, get_content, SIGNED_FILE, UNSIGNED_FILE};
+use crate::app::coin::Colour;
+use crate::app::components::accounts::structs::Account;
+use crate::app::components::work::{precompute::cancel_precompute, progress::work_label};
+use crate::app::constants::colours::RED;
+use crate::app::helpers::go_back;
+use crate::app::themes::get_subtitle_colour;
+use crate::app::userdata::UserData;
+use cursive::traits::{Nameable, Resizable, Scrollable};
+use cursive::utils::markup::StyledString;
+use cursive::views::{Checkbox, Dialog, DummyView, LinearLayout, ProgressBar, TextArea, TextView};
+use cursive::Cursive;
+use dagchat_core::crypto::pow::WorkControl;
+use dagchat_core::offline::Transaction;
+use dagchat_core::Error;
+
+pub fn show_sign(s: &mut Cursive) {
+    let data = &s.user_data::<UserData>().unwrap();
+    let colour = get_subtitle_colour(data.coins[data.coin_idx].colour());
+    let form = LinearLayout::vertical()
+        .child(TextView::new(StyledString::styled(
+            "Unsigned transaction",
+            colour,
+        )))
+        .child(
+            TextArea::new()
+                .content(default_path(UNSIGNED_FILE))
+                .with_name("path")
+                .max_width(80),
+        )
+        .child(DummyView)
+        .child(TextView::new(StyledString::styled(
+            "Save signed transaction to",
+            colour,
+        )))
+        .child(
+            TextArea::new()
+                .content(default_path(SIGNED_FILE))
+                .with_name("signedpath")
+                .max_width(80),
+        )
+        .child(DummyView)
+        .child(
+            LinearLayout::horizontal()
+                .child(Checkbox::new().with_name("withwork"))
+                .child(TextView::new(" Generate work on this device")),
+        );
+    s.add_layer(
+        Dialog::around(form)
+            .button("Sign", check_transaction)
+            .button("Back", go_back)
+            .title("Sign transaction")
+            .padding_lrtb(1, 1, 1, 0),
+    );
+}
+
+fn check_transaction(s: &mut Cursive) {
+    let path = get_content(s, "path");
+    let signed_path = get_content(s, "signedpath");
+    let with_work = s
+        .call_on_name("withwork", |view: &mut Checkbox| view.is_checked())
+        .unwrap();
+    let transaction = match Transaction::read(&path) {
+        Ok(transaction) => transaction,
+        Err(e) => {
+            s.add_layer(Dialog::info(StyledString::styled(
+                format!("Failed to read the transaction. Error: {}", e),
+                RED,
+            )));
+            return;
+        }
+    };
+    let data = &s.user_data::<UserData>().unwrap();
+    let coin_idx = match data
+        .coins
+        .iter()
+        .position(|coin| coin.name == transaction.coin)
+    {
+        Some(coin_idx) => coin_idx,
+        None => {
+            s.add_layer(Dialog::info(format!(
+                "Transactions for {} aren't supported.",
+                transaction.coin
+            )));
+            return;
+        }
+    };
+    let coin = &data.coins[coin_idx];
+    let colour = coin.colour();
+    let mut private_key: Option<[u8; 32]> = None;
+    for wallet in &data.wallets {
+        for index in &wallet.indexes {
+            let account = Account::with_index(wallet, *index, &coin.prefix);
+            if account.address == transaction.account {
+                private_key = Some(account.private_key);
+            }
+        }
+    }
+    let private_key = match private_key {
+        Some(private_key) => private_key,
+        None => {
+            s.add_layer(Dialog::info(format!(
+                "{} isn't in any of your wallets, so the transaction can't be signed here.",
+                transaction.account
+            )));
+            return;
+        }
+    };
+    // The online device wrote the file, so only what can be read from
+    // the blocks themselves is shown as fact.
+    let summary = match transaction.summary(coin) {
+        Ok(summary) => summary,
+        Err(e) => {
+            s.add_layer(Dialog::info(StyledString::styled(
+                format!("The transaction can't be signed. Error: {}", e),
+                RED,
+            )));
+            return;
+        }
+    };
+    let content = LinearLayout::vertical()
+        .child(TextView::new(StyledString::styled(
+            format!("Account\n{}\n\n{}", transaction.account, summary),
+            colour,
+        )))
+        .child(DummyView)
+        .child(TextView::new(StyledString::styled(
+            "Description (unverified)",
+            get_subtitle_colour(colour),
+        )))
+        .child(TextView::new(transaction.description.clone()));
+    s.add_layer(
+        Dialog::around(content.scrollable().max_height(24))
+            .button("Sign", move |s| {
+                s.pop_layer();
+                sign_transaction(
+                    s,
+                    transaction.clone(),
+                    private_key,
+                    coin_idx,
+                    with_work,
+                    signed_path.clone(),
+                );
+            })
+            .button("Back", go_back)
+            .title("Confirm transaction")
+            .max_width(80),
+    );
+}
+
+fn sign_transaction(
+    s: &mut Cursive,
+    transaction: Transaction,
+    private_key: [u8; 32],
+    coin_idx: usize,
+    with_work: bool,
+    path: String,
+) {
+    cancel_precompute(s);
+    let ticks = 1000;
+    let cb = s.cb_sink().clone();
+    let data = &s.user_data::<UserData>().unwrap();
+    let coin = data.coins[coin_idx].clone();
+    let control = WorkControl::default();
+    let cancel = control.clone();
+    s.add_layer(
+        Dialog::around(
+            ProgressBar::new()
+                .range(0, ticks)
+                .with_label(work_label(control.clone()))
+                .with_task(move |counter| {
+                    let result = transaction
+                        .sign(
+                            &private_key,
+                            &coin,
+                            with_work,
+                            &|n| counter.tick(n),
+                            &control,
+                        )
+                        .and_then(|signed| signed.write(&path));
+                    cb.send(Box::new(move |s| {
+                        s.set_autorefresh(false);
+                        s.pop_layer();
+                        match result {
+                            Ok(()) => {
+                                s.pop_layer();
+                                s.add_layer(
+                                    Dialog::info(format!(
+                                        "Saved the signed transaction to\n{}\n\nMove it to your online device to publish it.",
+                                        path
+                                    ))
+                                    .title("Transaction signed")
+                                    .max_width(80),
+                                );
+                            }
+                            Err(Error::Cancelled) => {
+                                s.add_layer(Dialog::info("Signing cancelled."))
+                            }
+                            Err(e) => s.add_layer(Dialog::info(StyledString::styled(
+                                format!("Failed to sign the transaction. Error: {}", e),
+                                RED,
+                            ))),
+                        }
+                    }))
+                    .unwrap();
+                })
+                .full_width(),
+        )
+        .title("Signing transaction")
+        .button("Cancel", move |_| cancel.cancel()),
+    );
+    s.set_autorefresh(true);
+}
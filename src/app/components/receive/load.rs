@@ -1,18 +1,20 @@
-use crate::app::components::inbox::ui::primary::show_inbox;
+use crate::app::components::inbox::ui::{primary::show_inbox, restorerep::show_restore_rep};
 use crate::app::constants::colours::RED;
 use crate::app::userdata::UserData;
-use crate::rpc::{accountinfo::*, incoming::find_incoming};
 use cursive::traits::Resizable;
 use cursive::utils::markup::StyledString;
 use cursive::views::{Dialog, ProgressBar};
 use cursive::Cursive;
+use dagchat_core::messages::representative::check_representative;
+use dagchat_core::rpc::{accountinfo::*, incoming::find_incoming};
 pub fn load_receivables(s: &mut Cursive) {
     let ticks = 1000;
 
     let cb = s.cb_sink().clone();
 
     let data = &s.user_data::<UserData>().unwrap();
-    let node_url = data.coins[data.coin_idx].network.node_url.clone();
+    let network = data.coins[data.coin_idx].network.clone();
+    let node_url = network.node_url.clone();
     let wallet = &data.wallets[data.wallet_idx];
     let target_address = wallet.accounts[wallet.acc_idx].address.clone();
     s.pop_layer();
@@ -21,11 +23,19 @@ pub fn load_receivables(s: &mut Cursive) {
             .range(0, ticks)
             .with_task(move |counter| {
                 let mut balance: u128 = 0;
+                let mut garbage_rep = None;
                 if let Ok(account_info) = get_account_info(&target_address, &node_url) {
                     balance = get_balance(&account_info);
+                    // Not being able to check is no reason to hold up the inbox.
+                    garbage_rep = check_representative(
+                        &target_address,
+                        &account_info.representative,
+                        &network,
+                    )
+                    .unwrap_or(None);
                 }
                 counter.tick(100);
-                let receivables = find_incoming(&target_address, &node_url, &counter);
+                let receivables = find_incoming(&target_address, &node_url, &|n| counter.tick(n));
                 cb.send(Box::new(move |s| {
                     let data = &mut s.user_data::<UserData>().unwrap();
                     let wallet = &mut data.wallets[data.wallet_idx];
@@ -34,6 +44,9 @@ pub fn load_receivables(s: &mut Cursive) {
                     if let Ok(receivables) = receivables {
                         account.receivables = receivables;
                         show_inbox(s);
+                        if let Some(garbage_rep) = garbage_rep {
+                            show_restore_rep(s, garbage_rep);
+                        }
                     } else {
                         account.receivables = vec![];
                         show_inbox(s);
@@ -1,4 +1,3 @@
 pub mod load;
 pub mod receiveblock;
-pub mod structs;
 pub mod ui;
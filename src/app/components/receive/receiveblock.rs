@@ -1,11 +1,12 @@
-use crate::app::coin::Coin;
-use crate::crypto::{
-    blocks::{get_block_hash, get_signed_block},
-    conversions::get_32_bytes,
-    keys::to_public_key,
-};
-use crate::rpc::{accountinfo::*, process::*};
 use cursive::utils::Counter;
+use dagchat_core::coin::Coin;
+use dagchat_core::crypto::{
+    blocks::{get_signed_block, Amount, Hash, StateBlock, Subtype},
+    keys::{PublicKey, SecretKey},
+    pow::WorkControl,
+};
+use dagchat_core::rpc::{accountinfo::*, process::*};
+use dagchat_core::Result;
 
 pub fn receive_block(
     private_key_bytes: &[u8; 32],
@@ -14,43 +15,36 @@ pub fn receive_block(
     address: &str,
     coin: &Coin,
     counter: &Counter,
-) -> Result<String, String> {
+    control: &WorkControl,
+) -> Result<String> {
+    let private_key = SecretKey(*private_key_bytes);
     let account_info_opt = get_account_info(address, &coin.network.node_url);
     counter.tick(300);
-    let mut last_block_hash = [0u8; 32];
+    let mut last_block_hash = Hash([0u8; 32]);
     let mut new_balance = amount;
-    let representative: [u8; 32];
-    let link = get_32_bytes(send_block);
+    let representative: PublicKey;
+    let link = Hash::from_hex(send_block)?;
 
     if let Ok(account_info) = account_info_opt {
-        last_block_hash = get_32_bytes(&account_info.frontier);
+        last_block_hash = Hash::from_hex(&account_info.frontier)?;
         let balance = get_balance(&account_info);
         new_balance = balance + amount;
-        representative = to_public_key(&account_info.representative);
+        representative = PublicKey::from_address(&account_info.representative)?;
     } else {
         // OPEN BLOCK
-        representative = to_public_key(&coin.network.default_rep);
+        representative = PublicKey::from_address(&coin.network.default_rep)?;
     }
 
     counter.tick(200);
-    let sub = String::from("receive");
-    let block_hash = get_block_hash(
-        private_key_bytes,
-        &representative,
-        &last_block_hash,
-        &link,
-        new_balance,
+    let block = StateBlock::new(
+        Subtype::Receive,
+        private_key.public_key(),
+        last_block_hash,
+        representative,
+        Amount(new_balance),
+        link,
     );
-    let signed_block = get_signed_block(
-        private_key_bytes,
-        &representative,
-        &last_block_hash,
-        &link,
-        new_balance,
-        &block_hash,
-        coin,
-        &sub,
-    )?;
+    let block = get_signed_block(block, &private_key, coin, control)?;
     counter.tick(400);
-    publish_block(signed_block, sub, &coin.network)
+    publish_block(&block, coin)
 }
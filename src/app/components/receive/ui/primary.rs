@@ -1,5 +1,6 @@
 use super::process::process_receive;
-use crate::app::components::messages::readmessage::read_message;
+use crate::app::coin::Colour;
+use crate::app::components::messages::ui::attachments::attachment_label;
 use crate::app::components::send::ui::primary::show_send;
 use crate::app::constants::colours::RED;
 use crate::app::constants::EMPTY_MSG;
@@ -9,13 +10,14 @@ use crate::app::{
     helpers::go_back,
     userdata::UserData,
 };
-use crate::crypto::conversions::display_to_dp;
 use cursive::views::{Dialog, DummyView, LinearLayout, SelectView, TextArea, TextView};
 use cursive::{
     traits::{Nameable, Resizable, Scrollable},
     utils::markup::StyledString,
     Cursive,
 };
+use dagchat_core::crypto::conversions::display_to_dp;
+use dagchat_core::messages::read::read_message;
 
 pub fn show_receivable(s: &mut Cursive, _name: &str) {
     let select = s.find_name::<SelectView<String>>("select").unwrap();
@@ -41,20 +43,18 @@ pub fn show_receivable(s: &mut Cursive, _name: &str) {
                 receive_label = String::from(" and mark read");
                 title = String::from("Message");
                 let message = receivable.message.as_mut().unwrap();
-                if message.plaintext.is_empty() {
+                if message.plaintext.is_empty() && message.attachment.is_none() {
                     // Potential feature: Confirm option with message length in chars (estimated)
                     // removes ability for attacks such as extremely long messages although probably
                     // not an issue. Harder to send a long message than read.
-                    let target = &message.head.contents.account;
-                    let root_hash = &message.root_hash;
-                    let blocks = message.blocks;
                     // Potential feature: Add loading screen + process_message()
                     // time taken to load a (long) message can be noticeable if node
                     // is under load.
-                    let read_res = read_message(private_key, target, root_hash, blocks, node_url);
-                    if let Ok(plaintext_res) = read_res {
-                        plaintext = plaintext_res;
-                        message.plaintext = plaintext.clone();
+                    let read_res = read_message(private_key, message, node_url);
+                    if let Ok(message_content) = read_res {
+                        plaintext = message_content.text.clone();
+                        message.plaintext = message_content.text;
+                        message.attachment = message_content.attachment;
                     } else {
                         plaintext =
                             format!("Failed to read message. Error: {}", read_res.err().unwrap());
@@ -76,8 +76,23 @@ pub fn show_receivable(s: &mut Cursive, _name: &str) {
                         .max_height(6),
                 );
                 content.add_child(DummyView);
+                if let Some(attachment) = receivable
+                    .message
+                    .as_ref()
+                    .and_then(|message| message.attachment.as_ref())
+                {
+                    content.add_child(TextView::new(StyledString::styled(
+                        "Attachment",
+                        coin.colour(),
+                    )));
+                    content.add_child(TextView::new(StyledString::styled(
+                        attachment_label(attachment),
+                        OFF_WHITE,
+                    )));
+                    content.add_child(DummyView);
+                }
             }
-            let colour = coin.colour;
+            let colour = coin.colour();
             if !(receivable.amount == 1 && receivable.message.is_some()) {
                 receive_label = format!("Receive{}", receive_label);
                 let amount = display_to_dp(
@@ -1,18 +1,26 @@
 use super::super::receiveblock::receive_block;
+use crate::app::coin::Colour;
 use crate::app::{
     components::{
         inbox::ui::primary::show_inbox,
         messages::{save::save_messages, structs::SavedMessage},
+        work::{
+            precompute::{cancel_precompute, precompute_work},
+            progress::work_label,
+        },
     },
     constants::{colours::RED, SHOW_TO_DP},
     userdata::UserData,
 };
-use crate::crypto::conversions::display_to_dp;
 use cursive::views::{Button, Dialog, HideableView, ProgressBar, SelectView, TextView};
 use cursive::{traits::Resizable, utils::markup::StyledString, Cursive};
+use dagchat_core::crypto::conversions::display_to_dp;
+use dagchat_core::crypto::pow::WorkControl;
+use dagchat_core::Error;
 use std::time::SystemTime;
 
 pub fn process_receive(s: &mut Cursive, mut idx: usize, all: bool) {
+    cancel_precompute(s);
     let data = &s.user_data::<UserData>().unwrap();
     let wallet = &data.wallets[data.wallet_idx];
     let account = &wallet.accounts[wallet.acc_idx];
@@ -22,11 +30,13 @@ pub fn process_receive(s: &mut Cursive, mut idx: usize, all: bool) {
         if let Some(non_msg_idx) = non_msg_idx {
             idx = non_msg_idx;
         } else {
+            let address = account.address.clone();
             s.set_autorefresh(false);
             s.call_on_name("receiveall", |view: &mut HideableView<Button>| {
                 view.set_visible(false);
             })
             .unwrap();
+            precompute_work(s, vec![address]);
             return;
         }
     }
@@ -37,98 +47,117 @@ pub fn process_receive(s: &mut Cursive, mut idx: usize, all: bool) {
     let coin = data.coins[data.coin_idx].clone();
     let ticks = 1000;
     let cb = s.cb_sink().clone();
-    s.add_layer(Dialog::around(
-        ProgressBar::new()
-            .range(0, ticks)
-            .with_task(move |counter| {
-                let mut error = String::from("");
-                if let Err(e) = receive_block(
-                    &private_key,
-                    &send_block_hash,
-                    amount,
-                    &address,
-                    &coin,
-                    &counter,
-                ) {
-                    error = e;
-                }
-                cb.send(Box::new(move |s| {
-                    if !error.is_empty() {
-                        s.set_autorefresh(false);
-                        s.pop_layer();
-                        s.pop_layer();
-                        show_inbox(s);
-                        s.add_layer(Dialog::info(StyledString::styled(
-                            format!("Receive failed. Error: {}", error),
-                            RED,
-                        )));
-                        return;
-                    }
-                    let mut select = s.find_name::<SelectView<String>>("select").unwrap();
-                    select.remove_item(idx);
-                    let mut balance = s.find_name::<TextView>("balance").unwrap();
-                    let data = &mut s.user_data::<UserData>().unwrap();
-                    let wallet = &mut data.wallets[data.wallet_idx];
-                    let account = &mut wallet.accounts[wallet.acc_idx];
-                    let receivable = &account.receivables[idx];
-                    let send_block_hash = receivable.hash.clone();
-                    let amount = receivable.amount;
-                    let has_message = { receivable.message.is_some() };
-                    let mut save_res = Ok(());
-                    if has_message && data.coins[data.coin_idx].network.save_messages {
-                        account.messages.as_mut().unwrap().push(SavedMessage {
-                            outgoing: false,
-                            address: receivable.source.clone(),
-                            timestamp: match SystemTime::now()
-                                .duration_since(SystemTime::UNIX_EPOCH)
-                            {
-                                Ok(n) => n.as_secs(),
-                                Err(_) => 0u64,
-                            },
-                            amount: display_to_dp(
-                                amount,
-                                SHOW_TO_DP,
-                                &coin.multiplier,
-                                &coin.ticker,
-                            ),
-                            hash: send_block_hash,
-                            plaintext: receivable.message.as_ref().unwrap().plaintext.clone(),
-                        });
-                        save_res = save_messages(s);
-                    }
-                    let data = &mut s.user_data::<UserData>().unwrap();
-                    let wallet = &mut data.wallets[data.wallet_idx];
-                    let account = &mut wallet.accounts[wallet.acc_idx];
-                    account.receivables.remove(idx);
-                    account.balance += amount;
-                    let bal = display_to_dp(
-                        account.balance,
-                        SHOW_TO_DP,
-                        &data.coins[data.coin_idx].multiplier,
-                        &data.coins[data.coin_idx].ticker,
-                    );
-                    let bal_text = format!("Balance: {}", bal);
-                    balance.set_content(StyledString::styled(
-                        bal_text,
-                        data.coins[data.coin_idx].colour,
-                    ));
-                    s.pop_layer();
-                    if all {
-                        return process_receive(s, 0, all);
-                    } else {
-                        s.pop_layer();
-                        s.set_autorefresh(false);
+    let control = WorkControl::default();
+    let cancel = control.clone();
+    s.add_layer(
+        Dialog::around(
+            ProgressBar::new()
+                .range(0, ticks)
+                .with_label(work_label(control.clone()))
+                .with_task(move |counter| {
+                    let mut error = String::from("");
+                    let mut cancelled = false;
+                    if let Err(e) = receive_block(
+                        &private_key,
+                        &send_block_hash,
+                        amount,
+                        &address,
+                        &coin,
+                        &counter,
+                        &control,
+                    ) {
+                        error = e.to_string();
+                        cancelled = matches!(e, Error::Cancelled);
                     }
-                    if save_res.is_err() {
-                        s.add_layer(
-                            Dialog::info(StyledString::styled(save_res.err().unwrap(), RED))
-                                .title("Failed to save messages"),
+                    cb.send(Box::new(move |s| {
+                        if cancelled {
+                            s.set_autorefresh(false);
+                            s.pop_layer();
+                            s.pop_layer();
+                            show_inbox(s);
+                            s.add_layer(Dialog::info("Receive cancelled."));
+                            return;
+                        }
+                        if !error.is_empty() {
+                            s.set_autorefresh(false);
+                            s.pop_layer();
+                            s.pop_layer();
+                            show_inbox(s);
+                            s.add_layer(Dialog::info(StyledString::styled(
+                                format!("Receive failed. Error: {}", error),
+                                RED,
+                            )));
+                            return;
+                        }
+                        let mut select = s.find_name::<SelectView<String>>("select").unwrap();
+                        select.remove_item(idx);
+                        let mut balance = s.find_name::<TextView>("balance").unwrap();
+                        let data = &mut s.user_data::<UserData>().unwrap();
+                        let wallet = &mut data.wallets[data.wallet_idx];
+                        let account = &mut wallet.accounts[wallet.acc_idx];
+                        let receivable = &account.receivables[idx];
+                        let send_block_hash = receivable.hash.clone();
+                        let amount = receivable.amount;
+                        let has_message = { receivable.message.is_some() };
+                        let mut save_res = Ok(());
+                        if has_message && data.coins[data.coin_idx].network.save_messages {
+                            account.messages.as_mut().unwrap().push(SavedMessage {
+                                outgoing: false,
+                                address: receivable.source.clone(),
+                                timestamp: match SystemTime::now()
+                                    .duration_since(SystemTime::UNIX_EPOCH)
+                                {
+                                    Ok(n) => n.as_secs(),
+                                    Err(_) => 0u64,
+                                },
+                                amount: display_to_dp(
+                                    amount,
+                                    SHOW_TO_DP,
+                                    &coin.multiplier,
+                                    &coin.ticker,
+                                ),
+                                hash: send_block_hash,
+                                plaintext: receivable.message.as_ref().unwrap().plaintext.clone(),
+                                attachment: receivable.message.as_ref().unwrap().attachment.clone(),
+                            });
+                            save_res = save_messages(s);
+                        }
+                        let data = &mut s.user_data::<UserData>().unwrap();
+                        let wallet = &mut data.wallets[data.wallet_idx];
+                        let account = &mut wallet.accounts[wallet.acc_idx];
+                        account.receivables.remove(idx);
+                        account.balance += amount;
+                        let bal = display_to_dp(
+                            account.balance,
+                            SHOW_TO_DP,
+                            &data.coins[data.coin_idx].multiplier,
+                            &data.coins[data.coin_idx].ticker,
                         );
-                    }
-                }))
-                .unwrap();
-            })
-            .full_width(),
-    ));
+                        let bal_text = format!("Balance: {}", bal);
+                        balance.set_content(StyledString::styled(
+                            bal_text,
+                            data.coins[data.coin_idx].colour(),
+                        ));
+                        s.pop_layer();
+                        if all {
+                            return process_receive(s, 0, all);
+                        } else {
+                            s.pop_layer();
+                            s.set_autorefresh(false);
+                            precompute_work(s, vec![address]);
+                        }
+                        if save_res.is_err() {
+                            s.add_layer(
+                                Dialog::info(StyledString::styled(save_res.err().unwrap(), RED))
+                                    .title("Failed to save messages"),
+                            );
+                        }
+                    }))
+                    .unwrap();
+                })
+                .full_width(),
+        )
+        .button("Cancel", move |_| cancel.cancel()),
+    );
     s.set_autorefresh(true);
 }
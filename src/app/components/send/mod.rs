@@ -1,3 +1,2 @@
 pub mod sendblock;
-mod sendmessage;
 pub mod ui;
@@ -1,52 +1,41 @@
-use crate::app::coin::Coin;
-use crate::crypto::{
-    address::get_address,
-    blocks::{get_block_hash, get_signed_block},
-    conversions::get_32_bytes,
-    keys::to_public_key,
-};
-use crate::rpc::accountinfo::{get_account_info, get_balance};
-use crate::rpc::process::publish_block;
 use cursive::utils::Counter;
+use dagchat_core::coin::Coin;
+use dagchat_core::crypto::{
+    blocks::{get_signed_block, Amount, Hash, StateBlock, Subtype},
+    keys::{PublicKey, SecretKey},
+    pow::WorkControl,
+};
+use dagchat_core::rpc::accountinfo::{get_account_info, get_balance};
+use dagchat_core::rpc::process::publish_block;
+use dagchat_core::Result;
 pub fn send(
     private_key_bytes: &[u8; 32],
     address: String,
     raw: u128,
     coin: &Coin,
     counter: &Counter,
-) -> Result<String, String> {
+    control: &WorkControl,
+    on_publish: &dyn Fn(),
+) -> Result<String> {
     // Derive sender's address
-    let sender_pub = ed25519_dalek::PublicKey::from(
-        &ed25519_dalek::SecretKey::from_bytes(private_key_bytes).unwrap(),
-    );
-    let sender_address = get_address(sender_pub.as_bytes(), Some(&coin.prefix));
+    let private_key = SecretKey(*private_key_bytes);
+    let sender = private_key.public_key();
+    let sender_address = sender.to_address(&coin.prefix);
 
     // Safe because account must be opened to have got this far
     let account_info = get_account_info(&sender_address, &coin.network.node_url)?;
 
-    let last_block_hash = get_32_bytes(&account_info.frontier);
-    let new_balance = get_balance(&account_info) - raw;
-    let representative = to_public_key(&account_info.representative);
-    let link = to_public_key(&address);
-    counter.tick(200);
-    let sub = String::from("send");
-    let block_hash = get_block_hash(
-        private_key_bytes,
-        &representative,
-        &last_block_hash,
-        &link,
-        new_balance,
+    let block = StateBlock::new(
+        Subtype::Send,
+        sender,
+        Hash::from_hex(&account_info.frontier)?,
+        PublicKey::from_address(&account_info.representative)?,
+        Amount(get_balance(&account_info) - raw),
+        Hash::from(PublicKey::from_address(&address)?),
     );
-    let signed_block = get_signed_block(
-        private_key_bytes,
-        &representative,
-        &last_block_hash,
-        &link,
-        new_balance,
-        &block_hash,
-        coin,
-        &sub,
-    )?;
+    counter.tick(200);
+    let block = get_signed_block(block, &private_key, coin, control)?;
     counter.tick(500);
-    publish_block(signed_block, sub, &coin.network)
+    on_publish();
+    publish_block(&block, coin)
 }
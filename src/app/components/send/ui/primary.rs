@@ -1,10 +1,16 @@
 use super::process::process_send;
+use crate::app::coin::Colour;
 use crate::app::components::addressbook::ui::primary::show_addressbook;
 use crate::app::components::inbox::ui::primary::show_inbox;
+use crate::app::components::messages::ui::{
+    attachments::attachment_label, journal::check_interrupted_send,
+};
+use crate::app::components::offline::ui::primary::default_path;
+use crate::app::components::work::progress::format_count;
+use crate::app::constants::colours::RED;
+use crate::app::helpers::go_back;
 use crate::app::themes::get_subtitle_colour;
 use crate::app::{clipboard::*, userdata::UserData};
-use crate::crypto::conversions::raw_to_whole;
-use crate::crypto::{address::validate_address, conversions::whole_to_raw};
 use cursive::views::{
     Button, Checkbox, Dialog, DummyView, HideableView, LinearLayout, TextArea, TextView, ViewRef,
 };
@@ -14,6 +20,17 @@ use cursive::{
     utils::markup::StyledString,
     Cursive,
 };
+use dagchat_core::crypto::conversions::raw_to_whole;
+use dagchat_core::crypto::pow::expected_attempts;
+use dagchat_core::crypto::{address::validate_address, conversions::whole_to_raw};
+use dagchat_core::messages::envelope::{
+    guess_mime_type, Attachment, Content, MAX_ATTACHMENT_LENGTH,
+};
+use dagchat_core::messages::multicast::MAX_RECIPIENTS;
+use dagchat_core::messages::send::message_block_count;
+use dagchat_core::uri::PaymentUri;
+use std::fs;
+use std::path::Path;
 
 pub fn show_send(s: &mut Cursive, with_message: bool) {
     let mut address = String::from("");
@@ -27,7 +44,11 @@ pub fn show_send(s: &mut Cursive, with_message: bool) {
 
     s.pop_layer();
 
-    let data = &s.user_data::<UserData>().unwrap();
+    let data = &mut s.user_data::<UserData>().unwrap();
+    if !with_message {
+        data.attachment = None;
+    }
+    let attachment = data.attachment.as_ref().map(attachment_label);
     let wallet = &data.wallets[data.wallet_idx];
     let account = &wallet.accounts[wallet.acc_idx];
     let balance = account.balance;
@@ -57,7 +78,7 @@ pub fn show_send(s: &mut Cursive, with_message: bool) {
         return;
     }
 
-    let sub_title_colour = get_subtitle_colour(data.coins[data.coin_idx].colour);
+    let sub_title_colour = get_subtitle_colour(data.coins[data.coin_idx].colour());
 
     let mut address_entry = TextArea::new();
     address_entry.set_cursor(address.len());
@@ -66,19 +87,31 @@ pub fn show_send(s: &mut Cursive, with_message: bool) {
         .with_name("address")
         .max_width(68);
 
+    let address_title = if with_message {
+        "Recipient Addresses (separate several with spaces)"
+    } else {
+        "Recipient Address"
+    };
     let mut form_content = LinearLayout::vertical()
         .child(TextView::new(StyledString::styled(
-            "Recipient Address",
+            address_title,
             sub_title_colour,
         )))
         .child(address_entry)
         .child(
             LinearLayout::horizontal()
-                .child(Button::new("Paste", |s| {
+                .child(Button::new("Paste", move |s| {
+                    let content = paste_clip(s);
+                    if PaymentUri::is_uri(&content) {
+                        paste_payment_uri(s, &content, with_message);
+                        return;
+                    }
                     let mut address: ViewRef<TextArea> = s.find_name("address").unwrap();
-                    address.set_content(paste_clip(s));
+                    address.set_content(content);
                 }))
-                .child(Button::new("Address book", show_addressbook)),
+                .child(Button::new("Address book", show_addressbook))
+                .child(DummyView)
+                .child(TextView::new("").with_name("paymentlabel")),
         )
         .child(DummyView);
     let title_content;
@@ -93,6 +126,13 @@ pub fn show_send(s: &mut Cursive, with_message: bool) {
             sub_title_colour,
         )));
         form_content.add_child(TextArea::new().with_name("message").max_width(80));
+        form_content.add_child(
+            LinearLayout::horizontal()
+                .child(Button::new("Attach file", show_attach))
+                .child(Button::new("Remove", |s| set_attachment(s, None)))
+                .child(DummyView)
+                .child(TextView::new(attachment.unwrap_or_default()).with_name("attachment")),
+        );
         form_content.add_child(DummyView);
         form_content.add_child(TextView::new(StyledString::styled(
             format!("Optional {}", ticker.trim()),
@@ -136,7 +176,8 @@ pub fn show_send(s: &mut Cursive, with_message: bool) {
                         message = String::from(view.get_content());
                     })
                     .unwrap();
-                    if message.trim().is_empty() {
+                    let has_attachment = s.user_data::<UserData>().unwrap().attachment.is_some();
+                    if message.trim().is_empty() && !has_attachment {
                         s.add_layer(Dialog::info(
                             "You must provide message content to send a message!",
                         ));
@@ -156,8 +197,26 @@ pub fn show_send(s: &mut Cursive, with_message: bool) {
                     s.add_layer(Dialog::info(content));
                     return;
                 }
-                if !validate_address(&address) {
-                    s.add_layer(Dialog::info("The recipient's address is invalid."));
+                // A message can go to several recipients at once.
+                let recipients = if with_message {
+                    parse_recipients(&address)
+                } else {
+                    vec![address]
+                };
+                if recipients.len() > MAX_RECIPIENTS {
+                    s.add_layer(Dialog::info(format!(
+                        "A message can be sent to at most {} recipients.",
+                        MAX_RECIPIENTS
+                    )));
+                    return;
+                }
+                if let Some(invalid) = recipients.iter().find(|r| !validate_address(r)) {
+                    let content = if recipients.len() == 1 {
+                        String::from("The recipient's address is invalid.")
+                    } else {
+                        format!("The recipient address {} is invalid.", invalid)
+                    };
+                    s.add_layer(Dialog::info(content));
                     return;
                 }
                 let mut raw: u128 = 0;
@@ -191,7 +250,7 @@ pub fn show_send(s: &mut Cursive, with_message: bool) {
                             return;
                         }
                     }
-                } else if message.is_empty() {
+                } else if !with_message {
                     // The user supplied no amount and it's not a message
                     s.add_layer(Dialog::info(format!(
                         "You must provide an amount of {} to send!",
@@ -199,10 +258,31 @@ pub fn show_send(s: &mut Cursive, with_message: bool) {
                     )));
                     return;
                 }
-                process_send(s, raw, address, message);
+                if raw.saturating_mul(recipients.len() as u128) > balance {
+                    s.add_layer(Dialog::info(format!(
+                        "Sending to {} recipients needs more than your account balance!",
+                        recipients.len()
+                    )));
+                    return;
+                }
+                if with_message && check_interrupted_send(s) {
+                    return;
+                }
+                let content = Content {
+                    text: message,
+                    attachment: s.user_data::<UserData>().unwrap().attachment.take(),
+                };
+                if content.attachment.is_some() || recipients.len() > 1 {
+                    confirm_message(s, raw, recipients, content);
+                } else {
+                    process_send(s, raw, recipients, content);
+                }
             }))
             .child(DummyView)
-            .child(Button::new("Back", show_inbox))
+            .child(Button::new("Back", |s| {
+                s.user_data::<UserData>().unwrap().attachment = None;
+                show_inbox(s);
+            }))
             .child(DummyView)
             .child(DummyView)
             .child(DummyView)
@@ -221,3 +301,158 @@ pub fn show_send(s: &mut Cursive, with_message: bool) {
         s.focus_name("message").unwrap();
     }
 }
+
+// Fills in the form from a nano: or ban: payment request, switching to
+// a message if the request has one.
+fn paste_payment_uri(s: &mut Cursive, uri: &str, with_message: bool) {
+    let payment = match PaymentUri::parse(uri) {
+        Ok(payment) => payment,
+        Err(e) => {
+            s.add_layer(Dialog::info(StyledString::styled(
+                format!("Failed to read the payment request. Error: {}", e),
+                RED,
+            )));
+            return;
+        }
+    };
+    let data = &s.user_data::<UserData>().unwrap();
+    let multiplier = data.coins[data.coin_idx].multiplier.clone();
+    s.call_on_name("address", |view: &mut TextArea| {
+        view.set_content(&payment.address)
+    });
+    if let Some(amount) = payment.amount {
+        s.call_on_name("amount", |view: &mut TextArea| {
+            view.set_content(raw_to_whole(&amount.to_string(), &multiplier))
+        });
+    }
+    if let Some(message) = payment.message {
+        if !with_message {
+            show_send(s, true);
+        }
+        s.call_on_name("message", |view: &mut TextArea| view.set_content(message));
+    }
+    if let Some(label) = payment.label {
+        s.call_on_name("paymentlabel", |view: &mut TextView| {
+            view.set_content(format!("Paying {}", label))
+        });
+    }
+}
+
+fn show_attach(s: &mut Cursive) {
+    let data = &s.user_data::<UserData>().unwrap();
+    let colour = get_subtitle_colour(data.coins[data.coin_idx].colour());
+    s.add_layer(
+        Dialog::around(
+            LinearLayout::vertical()
+                .child(TextView::new(StyledString::styled("File", colour)))
+                .child(
+                    TextArea::new()
+                        .content(default_path(""))
+                        .with_name("attachpath")
+                        .max_width(80),
+                ),
+        )
+        .button("Attach", |s| {
+            let path = s
+                .call_on_name("attachpath", |view: &mut TextArea| {
+                    String::from(view.get_content().trim())
+                })
+                .unwrap();
+            match read_attachment(&path) {
+                Ok(attachment) => {
+                    s.pop_layer();
+                    set_attachment(s, Some(attachment));
+                }
+                Err(e) => s.add_layer(Dialog::info(StyledString::styled(e, RED))),
+            }
+        })
+        .button("Back", go_back)
+        .title("Attach file"),
+    );
+}
+
+fn read_attachment(path: &str) -> Result<Attachment, String> {
+    let data = fs::read(path).map_err(|e| format!("Failed to read {}. Error: {}", path, e))?;
+    if data.len() > MAX_ATTACHMENT_LENGTH {
+        return Err(format!(
+            "The file is {} bytes. Attachments can be at most {} bytes, since every 32 bytes sent needs its own block.",
+            data.len(),
+            MAX_ATTACHMENT_LENGTH
+        ));
+    }
+    let filename = Path::new(path)
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    Ok(Attachment {
+        mime_type: String::from(guess_mime_type(&filename)),
+        filename,
+        data,
+    })
+}
+
+fn set_attachment(s: &mut Cursive, attachment: Option<Attachment>) {
+    let label = attachment.as_ref().map(attachment_label);
+    s.user_data::<UserData>().unwrap().attachment = attachment;
+    s.call_on_name("attachment", |view: &mut TextView| {
+        view.set_content(label.unwrap_or_default())
+    });
+}
+
+// Splits the address field of a message into its recipients, dropping
+// any repeats.
+fn parse_recipients(addresses: &str) -> Vec<String> {
+    let mut recipients: Vec<String> = vec![];
+    for address in addresses.split(|c: char| c.is_whitespace() || c == ',') {
+        if !address.is_empty() && !recipients.iter().any(|r| r == address) {
+            recipients.push(String::from(address));
+        }
+    }
+    recipients
+}
+
+// Attachments and extra recipients take many blocks, each needing its
+// own proof of work, so the cost is shown before starting.
+fn confirm_message(s: &mut Cursive, raw: u128, recipients: Vec<String>, content: Content) {
+    let data = &s.user_data::<UserData>().unwrap();
+    let options = data.message_options();
+    let blocks = message_block_count(&content, recipients.len(), &options);
+    let threshold = &data.coins[data.coin_idx].network.send_thresh;
+    let work = match u64::from_str_radix(threshold, 16) {
+        Ok(threshold) => format!(
+            ", about {} hashes of proof of work",
+            format_count(blocks as f64 * expected_attempts(threshold))
+        ),
+        Err(_) => String::new(),
+    };
+    let subject = match &content.attachment {
+        Some(attachment) => attachment_label(attachment),
+        None => String::from("this message"),
+    };
+    let to = if recipients.len() > 1 {
+        format!(" to {} recipients", recipients.len())
+    } else {
+        String::new()
+    };
+    let attachment = content.attachment.clone();
+    s.add_layer(
+        Dialog::text(format!(
+            "Sending {}{} takes {}{} blocks{}.",
+            subject,
+            to,
+            if options.random_padding { "up to " } else { "" },
+            blocks,
+            work
+        ))
+        .button("Send", move |s| {
+            s.pop_layer();
+            process_send(s, raw, recipients.clone(), content.clone());
+        })
+        .button("Back", move |s| {
+            s.pop_layer();
+            s.user_data::<UserData>().unwrap().attachment = attachment.clone();
+        })
+        .title("Send message")
+        .max_width(70),
+    );
+}
@@ -1,98 +1,166 @@
-use super::super::{sendblock::send, sendmessage::send_message};
+use super::super::sendblock::send;
 use super::sent::show_sent;
 use crate::app::components::inbox::ui::primary::show_inbox;
+use crate::app::components::work::{
+    precompute::{cancel_precompute, precompute_work},
+    progress::work_label,
+};
 use crate::app::{
-    components::messages::{save::save_messages, structs::SavedMessage},
+    components::messages::{
+        journal::send_journaled_message, save::save_messages, structs::SavedMessage,
+    },
     constants::{colours::RED, SHOW_TO_DP},
     userdata::UserData,
 };
-use crate::crypto::conversions::display_to_dp;
 use cursive::{
-    traits::Resizable,
+    traits::{Nameable, Resizable},
     views::{Dialog, ProgressBar},
     {utils::markup::StyledString, Cursive},
 };
+use dagchat_core::crypto::conversions::display_to_dp;
+use dagchat_core::crypto::pow::WorkControl;
+use dagchat_core::messages::envelope::Content;
+use dagchat_core::Error;
 use std::time::SystemTime;
 
-pub fn process_send(s: &mut Cursive, raw: u128, address: String, message: String) {
+// A message can go to several recipients, each sent raw. Anything else
+// has exactly one.
+pub fn process_send(s: &mut Cursive, raw: u128, recipients: Vec<String>, content: Content) {
+    cancel_precompute(s);
     let ticks = 1000;
     let cb = s.cb_sink().clone();
     let data = &mut s.user_data::<UserData>().unwrap();
     let wallet = &data.wallets[data.wallet_idx];
     let private_key_bytes = wallet.accounts[wallet.acc_idx].private_key;
     let coin = data.coins[data.coin_idx].clone();
+    let password = data.password.clone();
+    let options = data.message_options();
+    let control = WorkControl::default();
+    let cancel = control.clone();
+    // Once the blocks are being published the send can't be stopped.
+    let publishing_cb = cb.clone();
+    let on_publish = move || {
+        publishing_cb
+            .send(Box::new(|s| {
+                s.call_on_name("sending", |view: &mut Dialog| {
+                    view.clear_buttons();
+                    view.set_title("Publishing");
+                });
+            }))
+            .unwrap();
+    };
     s.pop_layer();
-    s.add_layer(Dialog::around(
-        ProgressBar::new()
-            .range(0, ticks)
-            .with_task(move |counter| {
-                let with_message = !message.is_empty();
-                let mut hash = String::from("");
-                let mut error = String::from("");
-                if !with_message {
-                    // Add error handling and message response
-                    if let Err(e) = send(&private_key_bytes, address.clone(), raw, &coin, &counter)
-                    {
-                        error = e;
-                    }
-                } else {
-                    let send_res = send_message(
-                        &private_key_bytes,
-                        address.clone(),
-                        raw,
-                        message.clone(),
-                        &coin,
-                        &counter,
-                    );
-                    if let Ok(response_hash) = send_res {
-                        hash = response_hash;
+    s.add_layer(
+        Dialog::around(
+            ProgressBar::new()
+                .range(0, ticks)
+                .with_label(work_label(control.clone()))
+                .with_task(move |counter| {
+                    let with_message = !content.text.is_empty() || content.attachment.is_some();
+                    let mut hashes = vec![];
+                    let mut error = String::from("");
+                    let mut cancelled = false;
+                    if !with_message {
+                        // Add error handling and message response
+                        if let Err(e) = send(
+                            &private_key_bytes,
+                            recipients[0].clone(),
+                            raw,
+                            &coin,
+                            &counter,
+                            &control,
+                            &on_publish,
+                        ) {
+                            error = e.to_string();
+                            cancelled = matches!(e, Error::Cancelled);
+                        }
                     } else {
-                        error = send_res.err().unwrap();
-                    }
-                }
-                cb.send(Box::new(move |s| {
-                    if !error.is_empty() {
-                        s.set_autorefresh(false);
-                        s.pop_layer();
-                        show_inbox(s);
-                        s.add_layer(Dialog::info(StyledString::styled(
-                            format!("Send failed. Error: {}", error),
-                            RED,
-                        )));
-                        return;
+                        let send_res = send_journaled_message(
+                            &private_key_bytes,
+                            &recipients,
+                            raw,
+                            &content,
+                            &options,
+                            &coin,
+                            &password,
+                            &|n| counter.tick(n),
+                            &control,
+                            &on_publish,
+                        );
+                        match send_res {
+                            Ok(response_hashes) => hashes = response_hashes,
+                            Err(e) => {
+                                error = e.to_string();
+                                cancelled = matches!(e, Error::Cancelled);
+                                if matches!(e, Error::Rejected(_)) {
+                                    error.push_str("\n\nThe send has been saved. You will be offered to resume it or to restore your representative the next time you unlock dagchat.");
+                                }
+                            }
+                        }
                     }
-                    let mut save_res = Ok(());
-                    let data = &mut s.user_data::<UserData>().unwrap();
-                    let wallet = &mut data.wallets[data.wallet_idx];
-                    let account = &mut wallet.accounts[wallet.acc_idx];
-                    account.balance -= raw;
-                    if with_message && data.coins[data.coin_idx].network.save_messages {
-                        account.messages.as_mut().unwrap().push(SavedMessage {
-                            outgoing: true,
-                            address: address.clone(),
-                            timestamp: match SystemTime::now()
+                    cb.send(Box::new(move |s| {
+                        if cancelled {
+                            s.set_autorefresh(false);
+                            s.pop_layer();
+                            show_inbox(s);
+                            s.add_layer(Dialog::info("Send cancelled."));
+                            return;
+                        }
+                        if !error.is_empty() {
+                            s.set_autorefresh(false);
+                            s.pop_layer();
+                            show_inbox(s);
+                            s.add_layer(Dialog::info(StyledString::styled(
+                                format!("Send failed. Error: {}", error),
+                                RED,
+                            )));
+                            return;
+                        }
+                        let mut save_res = Ok(());
+                        let data = &mut s.user_data::<UserData>().unwrap();
+                        let wallet = &mut data.wallets[data.wallet_idx];
+                        let account = &mut wallet.accounts[wallet.acc_idx];
+                        account.balance -= raw * recipients.len() as u128;
+                        let sender_address = account.address.clone();
+                        if with_message && data.coins[data.coin_idx].network.save_messages {
+                            let timestamp = match SystemTime::now()
                                 .duration_since(SystemTime::UNIX_EPOCH)
                             {
                                 Ok(n) => n.as_secs(),
                                 Err(_) => 0u64,
-                            },
-                            amount: display_to_dp(raw, SHOW_TO_DP, &coin.multiplier, &coin.ticker),
-                            hash,
-                            plaintext: message.clone(),
-                        });
-                        save_res = save_messages(s);
-                    }
-                    show_sent(s, with_message);
-                    if save_res.is_err() {
-                        s.add_layer(
-                            Dialog::info(StyledString::styled(save_res.err().unwrap(), RED))
-                                .title("Failed to save messages"),
-                        );
-                    }
-                }))
-                .unwrap();
-            })
-            .full_width(),
-    ));
+                            };
+                            let amount =
+                                display_to_dp(raw, SHOW_TO_DP, &coin.multiplier, &coin.ticker);
+                            // One entry for each recipient, as if each had
+                            // been sent the message on its own.
+                            for (address, hash) in recipients.iter().zip(hashes) {
+                                account.messages.as_mut().unwrap().push(SavedMessage {
+                                    outgoing: true,
+                                    address: address.clone(),
+                                    timestamp,
+                                    amount: amount.clone(),
+                                    hash,
+                                    plaintext: content.text.clone(),
+                                    attachment: content.attachment.clone(),
+                                });
+                            }
+                            save_res = save_messages(s);
+                        }
+                        show_sent(s, with_message);
+                        precompute_work(s, vec![sender_address]);
+                        if save_res.is_err() {
+                            s.add_layer(
+                                Dialog::info(StyledString::styled(save_res.err().unwrap(), RED))
+                                    .title("Failed to save messages"),
+                            );
+                        }
+                    }))
+                    .unwrap();
+                })
+                .full_width(),
+        )
+        .button("Cancel", move |_| cancel.cancel())
+        .with_name("sending"),
+    );
     s.set_autorefresh(true);
 }
@@ -1,2 +1 @@
-pub mod structs;
 pub mod ui;
@@ -0,0 +1,95 @@
+use crate::app::coin::Colour;
+use crate::app::components::work::{
+    precompute::cancel_precompute,
+    progress::{format_hashrate, work_label},
+};
+use crate::app::userdata::UserData;
+use cursive::traits::Resizable;
+use cursive::utils::markup::StyledString;
+use cursive::views::{Dialog, ProgressBar};
+use cursive::Cursive;
+use dagchat_core::crypto::pow::{benchmark, expected_attempts, WorkControl};
+use std::time::Duration;
+
+// The benchmark runs in short rounds so that the progress bar moves
+// and cancelling doesn't have to wait for a long measurement.
+const ROUNDS: usize = 10;
+const ROUND_LENGTH: Duration = Duration::from_millis(500);
+
+pub fn show_pow_benchmark(s: &mut Cursive) {
+    cancel_precompute(s);
+    let ticks = 1000;
+    let cb = s.cb_sink().clone();
+    let data = &s.user_data::<UserData>().unwrap();
+    let coin = &data.coins[data.coin_idx];
+    let colour = coin.colour();
+    let send_thresh = coin.network.send_thresh.clone();
+    let receive_thresh = coin.network.receive_thresh.clone();
+    let control = WorkControl::default();
+    let cancel = control.clone();
+    s.add_layer(
+        Dialog::around(
+            ProgressBar::new()
+                .range(0, ticks)
+                .with_label(work_label(control.clone()))
+                .with_task(move |counter| {
+                    let mut total = 0;
+                    let mut rounds = 0;
+                    for _ in 0..ROUNDS {
+                        if control.is_cancelled() {
+                            break;
+                        }
+                        total += benchmark(ROUND_LENGTH, &control);
+                        rounds += 1;
+                        counter.tick(ticks / ROUNDS);
+                    }
+                    let cancelled = control.is_cancelled();
+                    cb.send(Box::new(move |s| {
+                        s.set_autorefresh(false);
+                        s.pop_layer();
+                        if cancelled || rounds == 0 {
+                            s.add_layer(Dialog::info("Benchmark cancelled."));
+                            return;
+                        }
+                        let hashrate = total / rounds;
+                        let content = format!(
+                            "\nHashrate: {}\n\nExpected time to generate work\nSend/change block: {}\nReceive block: {}",
+                            format_hashrate(hashrate),
+                            expected_time(&send_thresh, hashrate),
+                            expected_time(&receive_thresh, hashrate)
+                        );
+                        s.add_layer(
+                            Dialog::info(StyledString::styled(content, colour))
+                                .title("Proof of Work benchmark")
+                                .max_width(80),
+                        );
+                    }))
+                    .unwrap();
+                })
+                .full_width(),
+        )
+        .title("Benchmarking proof of work")
+        .button("Cancel", move |_| cancel.cancel()),
+    );
+    s.set_autorefresh(true);
+}
+
+fn expected_time(threshold: &str, hashrate: u64) -> String {
+    let threshold = match u64::from_str_radix(threshold, 16) {
+        Ok(threshold) => threshold,
+        Err(_) => return format!("invalid threshold {}", threshold),
+    };
+    if hashrate == 0 {
+        return String::from("unknown");
+    }
+    let seconds = expected_attempts(threshold) / hashrate as f64;
+    if seconds < 1.0 {
+        String::from("under a second")
+    } else if seconds < 120.0 {
+        format!("~{:.0} seconds", seconds)
+    } else if seconds < 7200.0 {
+        format!("~{:.0} minutes", seconds / 60.0)
+    } else {
+        format!("~{:.0} hours", seconds / 3600.0)
+    }
+}
@@ -1,3 +1,4 @@
+mod benchmark;
 mod defaultrep;
 mod nodeurl;
 pub mod primary;
@@ -1,23 +1,28 @@
-use super::super::structs::WorkType;
+use super::benchmark::show_pow_benchmark;
 use super::defaultrep::{get_default_rep_info, set_default_rep};
 use super::nodeurl::{get_nodeurl_info, set_node_url};
-use super::savemessages::{get_save_message_info, set_save_messages};
+use super::savemessages::{
+    get_padding_info, get_save_message_info, get_sender_copies_info, set_bucket_blocks,
+    set_random_padding, set_save_messages, set_sender_copies,
+};
 use super::workserverurl::set_work_server_url;
 use super::worktype::{get_local_work_info, set_work_type};
 use crate::app::clipboard::paste_clip;
+use crate::app::coin::Colour;
 use crate::app::components::storage::ui::setup::setup_password;
 use crate::app::constants::colours::RED;
 use crate::app::helpers::go_back;
 use crate::app::themes::get_subtitle_colour;
 use crate::app::userdata::UserData;
-use crate::rpc::workgenerate::test_work_server;
 use cursive::utils::markup::StyledString;
 use cursive::view::Nameable;
 use cursive::views::{
-    Button, Dialog, DummyView, HideableView, LinearLayout, RadioGroup, ScreensView, TextArea,
-    TextView, ViewRef,
+    Button, Checkbox, Dialog, DummyView, HideableView, LinearLayout, RadioGroup, ScreensView,
+    TextArea, TextView, ViewRef,
 };
 use cursive::Cursive;
+use dagchat_core::network::WorkType;
+use dagchat_core::rpc::workgenerate::test_work_server;
 
 pub fn show_settings(s: &mut Cursive) {
     let data = &s.user_data::<UserData>().unwrap();
@@ -44,7 +49,38 @@ pub fn show_settings(s: &mut Cursive) {
         forget_button.select();
     }
 
-    let colour = get_subtitle_colour(coin.colour);
+    let mut bucket_blocks: RadioGroup<usize> = RadioGroup::new();
+    let mut bucket_buttons = vec![];
+    for (blocks, label) in [
+        (1, "Off"),
+        (4, "4 blocks"),
+        (8, "8 blocks"),
+        (16, "16 blocks"),
+    ] {
+        let mut button = bucket_blocks.button(blocks, label);
+        if data.bucket_blocks.max(1) == blocks {
+            button.select();
+        }
+        bucket_buttons.push(button);
+    }
+    bucket_blocks.set_on_change(set_bucket_blocks);
+    let mut bucket_row = LinearLayout::horizontal();
+    for button in bucket_buttons {
+        bucket_row.add_child(button);
+        bucket_row.add_child(DummyView);
+    }
+
+    let mut sender_copies = Checkbox::new().on_change(set_sender_copies);
+    if data.sender_copies {
+        sender_copies.check();
+    }
+
+    let mut random_padding = Checkbox::new().on_change(set_random_padding);
+    if data.random_padding {
+        random_padding.check();
+    }
+
+    let colour = get_subtitle_colour(coin.colour());
     let mut work_server_form = HideableView::new(
         LinearLayout::vertical()
             .child(DummyView)
@@ -177,7 +213,9 @@ pub fn show_settings(s: &mut Cursive) {
                             .child(DummyView)
                             .child(
                                 LinearLayout::horizontal()
-                                    .child(Button::new("Info", get_local_work_info)),
+                                    .child(Button::new("Info", get_local_work_info))
+                                    .child(DummyView)
+                                    .child(Button::new("Benchmark", show_pow_benchmark)),
                             ),
                     )
                     .title("Proof of Work"),
@@ -229,6 +267,14 @@ pub fn show_settings(s: &mut Cursive) {
                             .child(
                                 LinearLayout::horizontal()
                                     .child(Button::new("Info", get_save_message_info)),
+                            )
+                            .child(DummyView)
+                            .child(
+                                LinearLayout::horizontal()
+                                    .child(sender_copies)
+                                    .child(TextView::new(" Readable copies"))
+                                    .child(DummyView)
+                                    .child(Button::new("Info", get_sender_copies_info)),
                             ),
                     )
                     .title("Messages"),
@@ -281,6 +327,23 @@ pub fn show_settings(s: &mut Cursive) {
                     )
                     .title("Default Representative"),
                 )
+                .child(DummyView)
+                .child(
+                    Dialog::around(
+                        LinearLayout::vertical()
+                            .child(DummyView)
+                            .child(bucket_row)
+                            .child(DummyView)
+                            .child(
+                                LinearLayout::horizontal()
+                                    .child(random_padding)
+                                    .child(TextView::new(" Random fill"))
+                                    .child(DummyView)
+                                    .child(Button::new("Info", get_padding_info)),
+                            ),
+                    )
+                    .title("Message padding"),
+                )
                 .child(DummyView),
         )
         .title("Settings Page 3"),
@@ -1,3 +1,4 @@
+use crate::app::coin::Colour;
 use crate::app::{
     components::storage::save::save_to_storage, constants::colours::RED,
     themes::get_subtitle_colour, userdata::UserData,
@@ -7,7 +8,7 @@ use cursive::{utils::markup::StyledString, views::Dialog, Cursive};
 pub fn set_save_messages(s: &mut Cursive, save_messages: &bool) {
     let data = &mut s.user_data::<UserData>().unwrap();
     data.coins[data.coin_idx].network.save_messages = *save_messages;
-    let colour = get_subtitle_colour(data.coins[data.coin_idx].colour);
+    let colour = get_subtitle_colour(data.coins[data.coin_idx].colour());
     let saved = save_to_storage(s);
     if let Ok(_saved) = saved {
         s.add_layer(Dialog::info(StyledString::styled(
@@ -24,8 +25,78 @@ pub fn set_save_messages(s: &mut Cursive, save_messages: &bool) {
 
 pub fn get_save_message_info(s: &mut Cursive) {
     let data = &mut s.user_data::<UserData>().unwrap();
-    let colour = data.coins[data.coin_idx].colour;
+    let colour = data.coins[data.coin_idx].colour();
     s.add_layer(Dialog::info(
             StyledString::styled("\nYou can choose whether you want dagchat to Save & Encrypt the messages you have sent and received, for reference in the future. Saved messages can be read, filtered and searched via the <Messages log> button which is accessible from the inbox once you have loaded an account.", colour),
         ).title("Messages"));
 }
+
+pub fn set_sender_copies(s: &mut Cursive, sender_copies: bool) {
+    let data = &mut s.user_data::<UserData>().unwrap();
+    data.sender_copies = sender_copies;
+    let colour = get_subtitle_colour(data.coins[data.coin_idx].colour());
+    let saved = save_to_storage(s);
+    if let Ok(_saved) = saved {
+        s.add_layer(Dialog::info(StyledString::styled(
+            "Updated selection successfully.",
+            colour,
+        )));
+    } else {
+        s.add_layer(Dialog::info(StyledString::styled(
+            format!("Failed to save selection. {}", saved.err().unwrap()),
+            RED,
+        )));
+    }
+}
+
+pub fn get_sender_copies_info(s: &mut Cursive) {
+    let data = &mut s.user_data::<UserData>().unwrap();
+    let colour = data.coins[data.coin_idx].colour();
+    s.add_layer(Dialog::info(
+            StyledString::styled("\nOnly a message's recipients hold the key to read it, so if your saved messages are lost, so are the ones you sent. With Readable copies on, each message you send also includes a key for your own account, letting you recover it from the chain. This adds a few blocks to every message, and recipients need a version of dagchat that can read messages sent to several accounts.", colour),
+        ).title("Readable copies"));
+}
+
+pub fn set_bucket_blocks(s: &mut Cursive, bucket_blocks: &usize) {
+    let data = &mut s.user_data::<UserData>().unwrap();
+    data.bucket_blocks = *bucket_blocks;
+    let colour = get_subtitle_colour(data.coins[data.coin_idx].colour());
+    let saved = save_to_storage(s);
+    if let Ok(_saved) = saved {
+        s.add_layer(Dialog::info(StyledString::styled(
+            "Updated selection successfully.",
+            colour,
+        )));
+    } else {
+        s.add_layer(Dialog::info(StyledString::styled(
+            format!("Failed to save selection. {}", saved.err().unwrap()),
+            RED,
+        )));
+    }
+}
+
+pub fn set_random_padding(s: &mut Cursive, random_padding: bool) {
+    let data = &mut s.user_data::<UserData>().unwrap();
+    data.random_padding = random_padding;
+    let colour = get_subtitle_colour(data.coins[data.coin_idx].colour());
+    let saved = save_to_storage(s);
+    if let Ok(_saved) = saved {
+        s.add_layer(Dialog::info(StyledString::styled(
+            "Updated selection successfully.",
+            colour,
+        )));
+    } else {
+        s.add_layer(Dialog::info(StyledString::styled(
+            format!("Failed to save selection. {}", saved.err().unwrap()),
+            RED,
+        )));
+    }
+}
+
+pub fn get_padding_info(s: &mut Cursive) {
+    let data = &mut s.user_data::<UserData>().unwrap();
+    let colour = data.coins[data.coin_idx].colour();
+    s.add_layer(Dialog::info(
+            StyledString::styled("\nMessages are split into blocks of 32 bytes, so anyone watching the chain can tell roughly how long a message is from how many blocks it takes. Padding every message to a multiple of several blocks makes all messages within that size look the same, at the cost of publishing and generating work for the extra blocks. With Random fill on, each message is instead padded to a random number of blocks up to the end of its bucket, so that message lengths don't all land on bucket boundaries. It has no effect while padding is off.", colour),
+        ).title("Message padding"));
+}
@@ -1,12 +1,17 @@
 use super::structs::*;
-use crate::app::components::settings::structs::Network;
+use crate::app::components::accounts::structs::Account;
+use crate::app::components::messages::ui::journal::check_journals;
+use crate::app::components::wallets::structs::{LegacyWallet, Wallet};
 use crate::app::components::wallets::ui::primary::show_wallets;
+use crate::app::components::work::precompute::precompute_work;
 use crate::app::constants::{colours::RED, paths};
 use crate::app::constants::{AUTHOR, AUTHOR_ADDR};
 use crate::app::userdata::UserData;
-use crate::crypto::aes::decrypt_bytes;
 use cursive::views::Dialog;
 use cursive::{utils::markup::StyledString, Cursive};
+use dagchat_core::crypto::aes::decrypt_bytes;
+use dagchat_core::crypto::blocks::Hash;
+use dagchat_core::network::Network;
 
 pub fn load_with_password(s: &mut Cursive, password: &str) {
     let data = &mut s.user_data::<UserData>().unwrap();
@@ -22,10 +27,11 @@ pub fn load_with_password(s: &mut Cursive, password: &str) {
 
         // Load wallets
         if storage_data.storage_bytes.len() > StorageElements::WALLETS {
-            if let Ok(wallets) =
-                bincode::deserialize(&storage_data.storage_bytes[StorageElements::WALLETS])
-            {
+            let wallets_bytes = &storage_data.storage_bytes[StorageElements::WALLETS];
+            if let Ok(wallets) = serde_json::from_slice(wallets_bytes) {
                 data.wallets = wallets;
+            } else if let Ok(wallets) = bincode::deserialize::<Vec<LegacyWallet>>(wallets_bytes) {
+                data.wallets = wallets.into_iter().map(Wallet::from).collect();
             } else {
                 errors.push_str(" wallets,");
             }
@@ -76,9 +82,67 @@ pub fn load_with_password(s: &mut Cursive, password: &str) {
             errors.push_str(" settings,");
         }
 
+        // Load precomputed work
+        if storage_data.storage_bytes.len() > StorageElements::WORK_CACHE {
+            if let Ok(work_caches) = bincode::deserialize::<Vec<Vec<(Hash, u64)>>>(
+                &storage_data.storage_bytes[StorageElements::WORK_CACHE],
+            ) {
+                // Storage from a build with fewer coins has fewer caches.
+                for (coin, work_cache) in data.coins.iter().zip(work_caches) {
+                    coin.work_cache.extend(work_cache);
+                }
+            } else {
+                errors.push_str(" work cache,");
+            }
+        }
+
+        // Load sender copies setting
+        if storage_data.storage_bytes.len() > StorageElements::SENDER_COPIES {
+            if let Ok(sender_copies) =
+                bincode::deserialize(&storage_data.storage_bytes[StorageElements::SENDER_COPIES])
+            {
+                data.sender_copies = sender_copies;
+            } else {
+                errors.push_str(" settings,");
+            }
+        }
+
+        // Load message padding setting
+        if storage_data.storage_bytes.len() > StorageElements::BUCKET_BLOCKS {
+            if let Ok(bucket_blocks) = bincode::deserialize::<u64>(
+                &storage_data.storage_bytes[StorageElements::BUCKET_BLOCKS],
+            ) {
+                data.bucket_blocks = bucket_blocks as usize;
+            } else {
+                errors.push_str(" settings,");
+            }
+        }
+
+        // Load random padding setting
+        if storage_data.storage_bytes.len() > StorageElements::RANDOM_PADDING {
+            if let Ok(random_padding) =
+                bincode::deserialize(&storage_data.storage_bytes[StorageElements::RANDOM_PADDING])
+            {
+                data.random_padding = random_padding;
+            } else {
+                errors.push_str(" settings,");
+            }
+        }
+
+        // Only the selected account, as every account's work would keep
+        // the CPU busy for a long time.
+        let mut addresses = vec![];
+        let prefix = &data.coins[data.coin_idx].prefix;
+        if let Some(wallet) = data.wallets.get(data.wallet_idx) {
+            if let Some(index) = wallet.indexes.get(wallet.acc_idx) {
+                addresses.push(Account::with_index(wallet, *index, prefix).address);
+            }
+        }
         show_wallets(s);
+        precompute_work(s, addresses);
+        check_journals(s);
         if !errors.is_empty() {
-            let errors: String = errors.chars().into_iter().take(errors.len() - 1).collect();
+            let errors: String = errors.chars().take(errors.len() - 1).collect();
             s.add_layer(Dialog::info(StyledString::styled(
                 format!(
                     "Error(s) encountered parsing{} from {} - reset to default values.",
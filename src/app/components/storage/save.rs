@@ -1,7 +1,7 @@
 use crate::app::components::storage::structs::StorageData;
 use crate::app::{constants::paths, userdata::UserData};
-use crate::crypto::aes::encrypt_bytes;
 use cursive::Cursive;
+use dagchat_core::crypto::aes::encrypt_bytes;
 use std::fs;
 
 fn write_storage(encrypted_bytes: Vec<u8>) -> Result<(), String> {
@@ -25,7 +25,7 @@ pub fn save_to_storage(s: &mut Cursive) -> Result<(), String> {
     if data.wallets.is_empty() && data.lookup.is_empty() && data.addressbook.is_empty() {
         return write_storage(vec![]);
     }
-    let wallets_bytes = bincode::serialize(&data.wallets).unwrap();
+    let wallets_bytes = serde_json::to_vec(&data.wallets).unwrap();
     let lookup_bytes = bincode::serialize(&data.lookup).unwrap();
     let addressbook_bytes = bincode::serialize(&data.addressbook).unwrap();
     let mut networks = vec![];
@@ -33,12 +33,24 @@ pub fn save_to_storage(s: &mut Cursive) -> Result<(), String> {
         networks.push(&coin.network)
     }
     let networks_bytes = bincode::serialize(&networks).unwrap();
+    let mut work_caches = vec![];
+    for coin in &data.coins {
+        work_caches.push(coin.work_cache.entries())
+    }
+    let work_cache_bytes = bincode::serialize(&work_caches).unwrap();
+    let sender_copies_bytes = bincode::serialize(&data.sender_copies).unwrap();
+    let bucket_blocks_bytes = bincode::serialize(&(data.bucket_blocks as u64)).unwrap();
+    let random_padding_bytes = bincode::serialize(&data.random_padding).unwrap();
     let storage_data = StorageData {
         storage_bytes: vec![
             wallets_bytes,
             lookup_bytes,
             addressbook_bytes,
             networks_bytes,
+            work_cache_bytes,
+            sender_copies_bytes,
+            bucket_blocks_bytes,
+            random_padding_bytes,
         ],
     };
     let encoded: Vec<u8> = bincode::serialize(&storage_data).unwrap();
@@ -2,10 +2,16 @@ use serde::{Deserialize, Serialize};
 pub struct StorageElements;
 
 impl StorageElements {
+    // JSON, so that fields can be added to Wallet with serde defaults.
+    // Older storage holds LegacyWallets encoded with bincode.
     pub const WALLETS: usize = 0;
     pub const LOOKUP: usize = 1;
     pub const ADDRESSBOOK: usize = 2;
     pub const NETWORKS: usize = 3;
+    pub const WORK_CACHE: usize = 4;
+    pub const SENDER_COPIES: usize = 5;
+    pub const BUCKET_BLOCKS: usize = 6;
+    pub const RANDOM_PADDING: usize = 7;
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -7,9 +7,9 @@ use cursive::traits::{Nameable, Resizable};
 use cursive::views::{Dialog, DummyView, EditView, LinearLayout, TextView};
 use cursive::{align::HAlign, utils::markup::StyledString, Cursive};
 
-pub fn setup_password<F: 'static>(s: &mut Cursive, on_success: F)
+pub fn setup_password<F>(s: &mut Cursive, on_success: F)
 where
-    F: Fn(&mut Cursive),
+    F: Fn(&mut Cursive) + 'static,
 {
     let warning = StyledString::styled(
         "Always backup or write down your mnemonics, seeds or keys elsewhere in case you forget your password.", RED);
@@ -29,7 +29,7 @@ pub fn show_title(s: &mut Cursive) {
     let button = Button::new_raw("Start", move |s| {
         let coin = coin_group.selection();
         let vibrant = theme_group.selection();
-        set_theme(s, &*coin, *vibrant);
+        set_theme(s, &coin, *vibrant);
         if *coin == "banano" {
             s.with_user_data(|data: &mut UserData| {
                 data.coin_idx = Coins::BANANO;
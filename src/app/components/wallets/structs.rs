@@ -1,5 +1,7 @@
 use super::super::accounts::structs::Account;
-use crate::crypto::address::get_address;
+use dagchat_core::crypto::address::get_address;
+use dagchat_core::crypto::keys::Derivation;
+use dagchat_core::crypto::mnemonic::mnemonic_to_seed;
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -7,6 +9,13 @@ pub struct Wallet {
     pub name: String,
     pub mnemonic: String,
     pub seed: [u8; 32],
+    // The 64 byte seed of a wallet imported as BIP39, which its accounts
+    // are derived from in place of seed. Empty for every other wallet.
+    #[serde(default)]
+    pub bip39_seed: Vec<u8>,
+    // One of keys::Derivation. Wallets from before it existed are legacy.
+    #[serde(default)]
+    pub derivation: usize,
     pub indexes: Vec<u32>,
     #[serde(skip)]
     pub accounts: Vec<Account>,
@@ -20,6 +29,8 @@ impl Wallet {
             name,
             mnemonic,
             seed,
+            bip39_seed: vec![],
+            derivation: Derivation::LEGACY,
             indexes: vec![0],
             accounts: vec![],
             acc_idx: 0,
@@ -29,6 +40,34 @@ impl Wallet {
             .push(Account::with_index(&wallet, 0, prefix));
         wallet
     }
+    // The passphrase isn't stored, only the seed derived with it.
+    pub fn new_bip39(
+        mnemonic: String,
+        passphrase: &str,
+        derivation: usize,
+        name: String,
+        prefix: &str,
+    ) -> Wallet {
+        let mut wallet = Wallet {
+            name,
+            bip39_seed: mnemonic_to_seed(&mnemonic, passphrase).to_vec(),
+            derivation,
+            mnemonic,
+            seed: [0u8; 32],
+            indexes: vec![0],
+            accounts: vec![],
+            acc_idx: 0,
+        };
+        wallet
+            .accounts
+            .push(Account::with_index(&wallet, 0, prefix));
+        wallet
+    }
+
+    pub fn is_bip39(&self) -> bool {
+        !self.bip39_seed.is_empty()
+    }
+
     pub fn new_key(private_key: [u8; 32], name: String, prefix: &str) -> Wallet {
         // Use seed field for private key for private key wallets.
         let public_key = Wallet::get_public_key(&private_key);
@@ -36,6 +75,8 @@ impl Wallet {
             name,
             mnemonic: String::from(""),
             seed: private_key,
+            bip39_seed: vec![],
+            derivation: Derivation::LEGACY,
             indexes: vec![0],
             accounts: vec![],
             acc_idx: 0,
@@ -58,3 +99,27 @@ impl Wallet {
         public_key.to_bytes()
     }
 }
+
+// The layout wallets were stored in, with bincode, before BIP39 support.
+#[derive(Deserialize)]
+pub struct LegacyWallet {
+    pub name: String,
+    pub mnemonic: String,
+    pub seed: [u8; 32],
+    pub indexes: Vec<u32>,
+}
+
+impl From<LegacyWallet> for Wallet {
+    fn from(legacy: LegacyWallet) -> Wallet {
+        Wallet {
+            name: legacy.name,
+            mnemonic: legacy.mnemonic,
+            seed: legacy.seed,
+            bip39_seed: vec![],
+            derivation: Derivation::LEGACY,
+            indexes: legacy.indexes,
+            accounts: vec![],
+            acc_idx: 0,
+        }
+    }
+}
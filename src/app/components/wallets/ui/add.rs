@@ -1,5 +1,8 @@
 use super::super::structs::Wallet;
+use super::corrections::{complete_last_word, show_corrections, show_word_hint};
 use super::primary::show_wallets;
+use super::shares::show_from_shares;
+use crate::app::coin::Colour;
 use crate::app::components::{
     accounts::ui::select::load_current_account,
     storage::{save::save_to_storage, ui::setup::setup_password},
@@ -10,16 +13,17 @@ use crate::app::{
     helpers::get_name,
     userdata::UserData,
 };
-use crate::crypto::mnemonic::{seed_to_mnemonic, validate_mnemonic};
-use cursive::views::{Dialog, DummyView, EditView, LinearLayout, TextView, ViewRef};
+use cursive::views::{Dialog, DummyView, EditView, LinearLayout, RadioGroup, TextView, ViewRef};
 use cursive::{align::HAlign, traits::*, utils::markup::StyledString, Cursive};
+use dagchat_core::crypto::keys::Derivation;
+use dagchat_core::crypto::mnemonic::{mnemonic_to_entropy, seed_to_mnemonic, validate_mnemonic};
 use rand::RngCore;
 
 pub fn add_wallet(s: &mut Cursive) {
     s.pop_layer();
     let data = &s.user_data::<UserData>().unwrap();
     let coin = &data.coins[data.coin_idx].name;
-    let colour = data.coins[data.coin_idx].colour;
+    let colour = data.coins[data.coin_idx].colour();
 
     let name_input = EditView::new()
         .content(format!("Default {}", data.wallets.len() + 1))
@@ -47,6 +51,10 @@ pub fn add_wallet(s: &mut Cursive) {
                 let name = get_name(s);
                 from_seedorkey(s, String::from("private key"), name);
             })
+            .button("Shares", |s| {
+                let name = get_name(s);
+                show_from_shares(s, name);
+            })
             .button("Back", show_wallets)
             .title("Import wallet"),
     );
@@ -54,52 +62,107 @@ pub fn add_wallet(s: &mut Cursive) {
 
 fn show_from_mnemonic(s: &mut Cursive, name: String) {
     s.pop_layer();
-    let on_submit_name = name.clone();
+    let data = &s.user_data::<UserData>().unwrap();
+    let colour = data.coins[data.coin_idx].colour();
+    let mut interpretation: RadioGroup<bool> = RadioGroup::new();
+    let mut derivation: RadioGroup<usize> = RadioGroup::new();
+    let content = LinearLayout::vertical()
+        .child(
+            EditView::new()
+                .on_edit(show_word_hint)
+                .with_name("mnemonic")
+                .fixed_width(60),
+        )
+        .child(TextView::new("0 words").with_name("wordhint"))
+        .child(DummyView)
+        .child(TextView::new(StyledString::styled(
+            "Interpret the mnemonic as",
+            colour,
+        )))
+        .child(interpretation.button(false, "Legacy nano seed (24 words)"))
+        .child(interpretation.button(true, "BIP39 (12 to 24 words)"))
+        .child(DummyView)
+        .child(TextView::new(StyledString::styled(
+            "BIP39 passphrase (optional)",
+            colour,
+        )))
+        .child(EditView::new().secret().with_name("passphrase"))
+        .child(DummyView)
+        .child(TextView::new(StyledString::styled(
+            "BIP39 account derivation",
+            colour,
+        )))
+        .child(derivation.button(Derivation::BIP44, "BIP44 (Ledger and most BIP39 wallets)"))
+        .child(derivation.button(Derivation::LEGACY, "Legacy nano"));
     s.add_layer(
         Dialog::new()
-            .title("Enter your 24 word mnemonic")
+            .title("Enter your mnemonic")
             .padding_lrtb(1, 1, 1, 0)
-            .content(
-                EditView::new()
-                    .on_submit(move |s, mnemonic| {
-                        process_from_mnemonic(s, mnemonic, on_submit_name.clone())
-                    })
-                    .with_name("mnemonic")
-                    .fixed_width(29),
-            )
+            .content(content)
             .h_align(HAlign::Center)
             .button("Done", move |s| {
                 let mnemonic = s
                     .call_on_name("mnemonic", |view: &mut EditView| view.get_content())
                     .unwrap();
-                process_from_mnemonic(s, &mnemonic, name.clone());
+                let passphrase = s
+                    .call_on_name("passphrase", |view: &mut EditView| view.get_content())
+                    .unwrap();
+                let bip39 = *interpretation.selection();
+                let derivation = *derivation.selection();
+                process_from_mnemonic(s, &mnemonic, &passphrase, bip39, derivation, name.clone());
             })
+            .button("Complete word", complete_last_word)
             .button("Paste", |s| {
+                let content = paste_clip(s);
                 let mut mnemonic: ViewRef<EditView> = s.find_name("mnemonic").unwrap();
-                mnemonic.set_content(paste_clip(s));
+                mnemonic.set_content(&content);
+                drop(mnemonic);
+                show_word_hint(s, &content, 0);
             })
             .button("Back", show_wallets),
     );
 }
 
-fn process_from_mnemonic(s: &mut Cursive, mnemonic: &str, name: String) {
-    let seed = validate_mnemonic(mnemonic);
-    let content;
-    if !mnemonic.is_empty() && seed.is_some() {
-        let seed_bytes = seed.unwrap();
-        let data = &s.user_data::<UserData>().unwrap();
-        let wallet = Wallet::new(
-            mnemonic.to_string(),
-            seed_bytes,
-            name,
-            &data.coins[data.coin_idx].prefix,
-        );
+fn process_from_mnemonic(
+    s: &mut Cursive,
+    mnemonic: &str,
+    passphrase: &str,
+    bip39: bool,
+    derivation: usize,
+    name: String,
+) {
+    let mnemonic = mnemonic
+        .split_whitespace()
+        .collect::<Vec<&str>>()
+        .join(" ")
+        .to_lowercase();
+    let data = &s.user_data::<UserData>().unwrap();
+    let prefix = data.coins[data.coin_idx].prefix.clone();
+    if bip39 {
+        if mnemonic_to_entropy(&mnemonic).is_none() {
+            show_corrections(s, &mnemonic);
+            return;
+        }
+        let wallet = Wallet::new_bip39(mnemonic, passphrase, derivation, name, &prefix);
+        setup_wallet(s, wallet, |s| {
+            import_success(
+                s,
+                "Successfully imported wallet from BIP39 mnemonic phrase.",
+            )
+        });
+    } else if !passphrase.is_empty() {
+        s.add_layer(Dialog::info("Only BIP39 mnemonics can have a passphrase."));
+    } else if let Some(seed_bytes) = validate_mnemonic(&mnemonic) {
+        let wallet = Wallet::new(mnemonic, seed_bytes, name, &prefix);
         setup_wallet(s, wallet, |s| {
             import_success(s, "Successfully imported wallet from mnemonic phrase.")
         });
+    } else if mnemonic_to_entropy(&mnemonic).is_some() {
+        s.add_layer(Dialog::info(
+            "Legacy nano mnemonics are 24 words long. Choose BIP39 to import a shorter mnemonic.",
+        ));
     } else {
-        content = "The mnemonic you entered was not valid.";
-        s.add_layer(Dialog::info(content));
+        show_corrections(s, &mnemonic);
     }
 }
 
@@ -177,7 +240,7 @@ fn process_from_seedorkey(s: &mut Cursive, sork_raw: String, seed_or_key: &str,
 pub fn new_wallet_name(s: &mut Cursive) {
     s.pop_layer();
     let data = &s.user_data::<UserData>().unwrap();
-    let colour = data.coins[data.coin_idx].colour;
+    let colour = data.coins[data.coin_idx].colour();
     let name_input = EditView::new()
         .on_submit(new_wallet)
         .content(format!("Default {}", data.wallets.len() + 1))
@@ -217,9 +280,9 @@ pub fn new_wallet(s: &mut Cursive, name: &str) {
         create_success(s, mnemonic.clone(), hex::encode(seed_bytes))
     });
 }
-fn setup_wallet<F: 'static>(s: &mut Cursive, wallet: Wallet, on_success: F)
+pub fn setup_wallet<F>(s: &mut Cursive, wallet: Wallet, on_success: F)
 where
-    F: Fn(&mut Cursive),
+    F: Fn(&mut Cursive) + 'static,
 {
     s.pop_layer();
     let data = &mut s.user_data::<UserData>().unwrap();
@@ -242,7 +305,7 @@ where
     }
 }
 
-fn import_success(s: &mut Cursive, content: &str) {
+pub fn import_success(s: &mut Cursive, content: &str) {
     s.add_layer(
         Dialog::around(TextView::new(content).max_width(80))
             .button("Load", load_current_account)
@@ -256,11 +319,11 @@ fn import_success(s: &mut Cursive, content: &str) {
 fn create_success(s: &mut Cursive, mnemonic: String, seed: String) {
     s.pop_layer();
     let data = &mut s.user_data::<UserData>().unwrap();
-    let mut content = StyledString::styled("\nMnemonic\n", data.coins[data.coin_idx].colour);
+    let mut content = StyledString::styled("\nMnemonic\n", data.coins[data.coin_idx].colour());
     content.append(StyledString::styled(&mnemonic, OFF_WHITE));
     content.append(StyledString::styled(
         "\n\nSeed\n",
-        data.coins[data.coin_idx].colour,
+        data.coins[data.coin_idx].colour(),
     ));
     content.append(StyledString::styled(&seed, OFF_WHITE));
     s.add_layer(
@@ -1,6 +1,10 @@
-use crate::app::{clipboard::*, constants::colours::RED, helpers::go_back, userdata::UserData};
+use super::shares::show_split;
+use crate::app::{
+    clipboard::*, constants::colours::RED, helpers::go_back, qr::show_secret_qr, userdata::UserData,
+};
 use cursive::views::{Dialog, DummyView, LinearLayout, OnEventView, SelectView, TextView};
 use cursive::{align::HAlign, traits::*, utils::markup::StyledString, Cursive};
+use dagchat_core::crypto::keys::{coin_type, Derivation};
 
 pub fn backup_wallet(s: &mut Cursive) {
     let eventview = s
@@ -26,44 +30,71 @@ pub fn backup_wallet(s: &mut Cursive) {
     .title("Backup wallet");
     if !wallet.mnemonic.is_empty() {
         let mnemonic = wallet.mnemonic.clone();
+        let bip39 = wallet.is_bip39();
+        let path = if wallet.derivation == Derivation::BIP44 {
+            format!(
+                "m/44'/{}'/index'",
+                coin_type(&data.coins[data.coin_idx].prefix)
+            )
+        } else {
+            String::from("legacy nano")
+        };
         content.add_button("Mnemonic", move |s| {
             let mnemonic = mnemonic.clone();
+            let mut layout = LinearLayout::vertical()
+                .child(DummyView)
+                .child(TextView::new(&mnemonic));
+            if bip39 {
+                layout.add_child(DummyView);
+                layout.add_child(TextView::new(StyledString::styled(
+                    format!("This is a BIP39 mnemonic with {} account derivation. Restoring it also needs the passphrase, if it was imported with one.", path),
+                    RED,
+                )));
+            }
             s.add_layer(
-                Dialog::around(
-                    LinearLayout::vertical()
-                        .child(DummyView)
-                        .child(TextView::new(&mnemonic)),
-                )
+                Dialog::around(layout)
                 .h_align(HAlign::Center)
-                .button("Copy", move |s| {
-                    s.pop_layer();
-                    s.pop_layer();
-                    copy_to_clip(s, mnemonic.clone())
+                .button("Copy", {
+                    let mnemonic = mnemonic.clone();
+                    move |s| {
+                        s.pop_layer();
+                        s.pop_layer();
+                        copy_to_clip(s, mnemonic.clone())
+                    }
                 })
+                .button("QR code", move |s| show_secret_qr(s, "Mnemonic", mnemonic.clone()))
                 .button("Back", go_back)
                 .title("Mnemonic")
                 .max_width(80),
             );
         });
-        let seed = hex::encode(wallet.seed);
-        content.add_button("Hex seed", move |s| {
-            let seed = seed.clone();
-            s.add_layer(
-                Dialog::around(
-                    LinearLayout::vertical()
-                        .child(DummyView)
-                        .child(TextView::new(&seed)),
-                )
-                .h_align(HAlign::Center)
-                .button("Copy", move |s| {
-                    s.pop_layer();
-                    s.pop_layer();
-                    copy_to_clip(s, seed.clone())
-                })
-                .button("Back", go_back)
-                .title("Seed"),
-            );
-        });
+        // A BIP39 wallet's seed comes from the mnemonic and passphrase,
+        // and can't be imported on its own.
+        if !bip39 {
+            let seed = hex::encode(wallet.seed);
+            content.add_button("Hex seed", move |s| {
+                let seed = seed.clone();
+                s.add_layer(
+                    Dialog::around(
+                        LinearLayout::vertical()
+                            .child(DummyView)
+                            .child(TextView::new(&seed)),
+                    )
+                    .h_align(HAlign::Center)
+                    .button("Copy", {
+                        let seed = seed.clone();
+                        move |s| {
+                            s.pop_layer();
+                            s.pop_layer();
+                            copy_to_clip(s, seed.clone())
+                        }
+                    })
+                    .button("QR code", move |s| show_secret_qr(s, "Seed", seed.clone()))
+                    .button("Back", go_back)
+                    .title("Seed"),
+                );
+            });
+        }
     } else {
         let private_key = hex::encode(wallet.seed);
         content.add_button("Private key", move |s| {
@@ -75,16 +106,31 @@ pub fn backup_wallet(s: &mut Cursive) {
                         .child(TextView::new(&private_key)),
                 )
                 .h_align(HAlign::Center)
-                .button("Copy", move |s| {
-                    s.pop_layer();
-                    s.pop_layer();
-                    copy_to_clip(s, private_key.clone())
+                .button("Copy", {
+                    let private_key = private_key.clone();
+                    move |s| {
+                        s.pop_layer();
+                        s.pop_layer();
+                        copy_to_clip(s, private_key.clone())
+                    }
+                })
+                .button("QR code", move |s| {
+                    show_secret_qr(s, "Private key", private_key.clone())
                 })
                 .button("Back", go_back)
                 .title("Private key"),
             );
         });
     }
+    if wallet.is_bip39() {
+        content.add_button("Shares", |s| {
+            s.add_layer(Dialog::info(
+                "BIP39 wallets can't be split into shares. Back up the mnemonic and passphrase instead.",
+            ))
+        });
+    } else {
+        content.add_button("Shares", move |s| show_split(s, selected_idx));
+    }
     content.add_button("Back", go_back);
 
     s.add_layer(content.max_width(80));
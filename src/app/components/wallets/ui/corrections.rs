@@ -0,0 +1,147 @@
+use crate::app::coin::Colour;
+use crate::app::helpers::go_back;
+use crate::app::userdata::UserData;
+use cursive::traits::{Resizable, Scrollable};
+use cursive::utils::markup::StyledString;
+use cursive::views::{Dialog, DummyView, EditView, LinearLayout, SelectView, TextView};
+use cursive::Cursive;
+use dagchat_core::crypto::mnemonic::{
+    complete_word, single_word_corrections, unknown_words, WORD_COUNTS,
+};
+
+// Completions shown for the word being typed.
+const MAX_COMPLETIONS: usize = 6;
+
+// Called as the mnemonic is edited: shows completions for the word
+// being typed and any earlier words that aren't in the word list.
+pub fn show_word_hint(s: &mut Cursive, content: &str, _cursor: usize) {
+    let content = content.to_lowercase();
+    let words: Vec<&str> = content.split_whitespace().collect();
+    let mut hint = format!("{} words", words.len());
+    let typing = if content.ends_with(char::is_whitespace) {
+        None
+    } else {
+        words.last().copied()
+    };
+    let finished = if typing.is_some() {
+        words.len() - 1
+    } else {
+        words.len()
+    };
+    let unknown: Vec<String> = unknown_words(&words[..finished].join(" "))
+        .iter()
+        .map(|unknown| format!("{} ({})", unknown.position, unknown.word))
+        .collect();
+    if !unknown.is_empty() {
+        hint.push_str(&format!(" - not in word list: {}", unknown.join(", ")));
+    }
+    if let Some(typing) = typing {
+        let completions = complete_word(typing);
+        if completions.is_empty() {
+            hint.push_str(&format!("\nNo words start with \"{}\"", typing));
+        } else if completions.len() <= MAX_COMPLETIONS {
+            hint.push_str(&format!("\n{}", completions.join(" ")));
+        }
+    }
+    s.call_on_name("wordhint", |view: &mut TextView| view.set_content(hint));
+}
+
+// Finishes the word being typed when only one word starts that way.
+pub fn complete_last_word(s: &mut Cursive) {
+    s.call_on_name("mnemonic", |view: &mut EditView| {
+        let content = view.get_content().to_lowercase();
+        if content.ends_with(char::is_whitespace) {
+            return;
+        }
+        let mut words: Vec<&str> = content.split_whitespace().collect();
+        if let Some(last) = words.pop() {
+            let completions = complete_word(last);
+            if completions.len() == 1 {
+                words.push(completions[0]);
+                let completed = format!("{} ", words.join(" "));
+                view.set_cursor(completed.len());
+                view.set_content(completed);
+            }
+        }
+    });
+    let content = s
+        .call_on_name("mnemonic", |view: &mut EditView| view.get_content())
+        .unwrap();
+    show_word_hint(s, &content, 0);
+}
+
+// Explains why a mnemonic isn't valid, offering any single word
+// replacements that would make its checksum pass.
+pub fn show_corrections(s: &mut Cursive, mnemonic: &str) {
+    let data = &s.user_data::<UserData>().unwrap();
+    let colour = data.coins[data.coin_idx].colour();
+    let word_count = mnemonic.split_whitespace().count();
+    let mut content = String::new();
+    if !WORD_COUNTS.contains(&word_count) {
+        content.push_str(&format!(
+            "The mnemonic has {} words, but mnemonics have 12, 15, 18, 21 or 24.\n\n",
+            word_count
+        ));
+    }
+    let unknown = unknown_words(mnemonic);
+    for word in &unknown {
+        content.push_str(&format!(
+            "Word {} \"{}\" isn't in the word list.",
+            word.position, word.word
+        ));
+        if word.suggestions.is_empty() {
+            content.push_str(" No words are close to it.\n");
+        } else {
+            content.push_str(&format!(
+                " Closest words: {}\n",
+                word.suggestions.join(", ")
+            ));
+        }
+    }
+    if unknown.is_empty() && WORD_COUNTS.contains(&word_count) {
+        content.push_str("Every word is in the word list, but the checksum doesn't match. Check the order of the words and look for one written down wrongly.\n");
+    }
+
+    let corrections = single_word_corrections(mnemonic);
+    let mut layout =
+        LinearLayout::vertical().child(TextView::new(StyledString::styled(content, colour)));
+    if !corrections.is_empty() {
+        let words: Vec<String> = mnemonic.split_whitespace().map(String::from).collect();
+        let mut select = SelectView::<(usize, &'static str)>::new().on_submit(
+            move |s, (position, replacement)| {
+                let mut corrected = words.clone();
+                corrected[position - 1] = String::from(*replacement);
+                let corrected = corrected.join(" ");
+                s.call_on_name("mnemonic", |view: &mut EditView| {
+                    view.set_content(&corrected)
+                });
+                show_word_hint(s, &corrected, 0);
+                s.pop_layer();
+            },
+        );
+        let original: Vec<&str> = mnemonic.split_whitespace().collect();
+        for (position, replacement) in corrections {
+            select.add_item(
+                format!(
+                    "Word {}: {} -> {}",
+                    position,
+                    original[position - 1],
+                    replacement
+                ),
+                (position, replacement),
+            );
+        }
+        layout.add_child(DummyView);
+        layout.add_child(TextView::new(StyledString::styled(
+            "Changing one of these words gives a valid mnemonic. Only pick one that matches what you wrote down:",
+            colour,
+        )));
+        layout.add_child(select.scrollable().max_height(8));
+    }
+    s.add_layer(
+        Dialog::around(layout)
+            .button("Back", go_back)
+            .title("The mnemonic isn't valid")
+            .max_width(80),
+    );
+}
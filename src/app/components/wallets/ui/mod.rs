@@ -1,5 +1,8 @@
 pub mod add;
 pub mod backup;
+pub mod corrections;
 pub mod primary;
 pub mod remove;
 pub mod select;
+pub mod shares;
+pub mod vanity;
@@ -3,9 +3,11 @@ use super::{
     backup::backup_wallet,
     remove::remove_wallet,
     select::select_wallet,
+    vanity::show_vanity,
 };
 use crate::app::components::{
-    settings::ui::primary::show_settings, title::ui::primary::show_title,
+    offline::ui::primary::show_offline, settings::ui::primary::show_settings,
+    title::ui::primary::show_title,
 };
 use crate::app::userdata::UserData;
 use cursive::event::{Event, EventResult, EventTrigger, MouseEvent};
@@ -19,10 +21,12 @@ pub fn show_wallets(s: &mut Cursive) {
     let buttons = LinearLayout::vertical()
         .child(Button::new("Import", add_wallet))
         .child(Button::new("Create", new_wallet_name))
+        .child(Button::new("Vanity", show_vanity))
         .child(Button::new("Backup", backup_wallet))
         .child(Button::new("Delete", remove_wallet))
         .child(DummyView)
         .child(Button::new("Settings", show_settings))
+        .child(Button::new("Offline", show_offline))
         .child(Button::new("Back", |s| {
             s.pop_layer();
             show_title(s);
@@ -31,12 +35,10 @@ pub fn show_wallets(s: &mut Cursive) {
 
     let mut select = SelectView::<String>::new().on_submit(select_wallet);
 
-    let mut i = 1;
     let data = &s.user_data::<UserData>().unwrap();
-    for wallet in &data.wallets {
-        let tag = format!("{}. {}", i, wallet.name);
+    for (i, wallet) in data.wallets.iter().enumerate() {
+        let tag = format!("{}. {}", i + 1, wallet.name);
         select.add_item_str(&tag);
-        i += 1;
     }
     let select = OnEventView::new(select).on_pre_event_inner(EventTrigger::mouse(), |s, e| {
         if let &Event::Mouse {
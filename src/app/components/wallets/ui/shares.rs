@@ -0,0 +1,191 @@
+use super::super::structs::Wallet;
+use super::add::{import_success, setup_wallet};
+use super::primary::show_wallets;
+use crate::app::coin::Colour;
+use crate::app::{
+    clipboard::*,
+    constants::colours::{OFF_WHITE, RED},
+    helpers::go_back,
+    userdata::UserData,
+};
+use cursive::traits::{Nameable, Resizable, Scrollable};
+use cursive::utils::markup::StyledString;
+use cursive::views::{Dialog, DummyView, EditView, LinearLayout, TextArea, TextView, ViewRef};
+use cursive::{align::HAlign, Cursive};
+use dagchat_core::crypto::mnemonic::seed_to_mnemonic;
+use dagchat_core::crypto::shamir::{combine_shares, split_secret, Share, ShareKind};
+
+pub fn show_split(s: &mut Cursive, wallet_idx: usize) {
+    let data = &s.user_data::<UserData>().unwrap();
+    let colour = data.coins[data.coin_idx].colour();
+    let content = LinearLayout::vertical()
+        .child(DummyView)
+        .child(TextView::new(StyledString::styled(
+            "Shares needed to restore",
+            colour,
+        )))
+        .child(EditView::new().content("2").with_name("threshold"))
+        .child(DummyView)
+        .child(TextView::new(StyledString::styled("Total shares", colour)))
+        .child(EditView::new().content("3").with_name("shares"));
+    s.add_layer(
+        Dialog::around(content)
+            .button("Split", move |s| {
+                let threshold = s
+                    .call_on_name("threshold", |view: &mut EditView| view.get_content())
+                    .unwrap();
+                let shares = s
+                    .call_on_name("shares", |view: &mut EditView| view.get_content())
+                    .unwrap();
+                match (threshold.trim().parse(), shares.trim().parse()) {
+                    (Ok(threshold), Ok(shares)) => {
+                        s.pop_layer();
+                        show_shares(s, wallet_idx, threshold, shares);
+                    }
+                    _ => s.add_layer(Dialog::info("Enter the number of shares as whole numbers.")),
+                }
+            })
+            .button("Back", go_back)
+            .title("Split into shares")
+            .max_width(80),
+    );
+}
+
+fn show_shares(s: &mut Cursive, wallet_idx: usize, threshold: u8, shares: u8) {
+    let data = &s.user_data::<UserData>().unwrap();
+    let coin = &data.coins[data.coin_idx];
+    let colour = coin.colour();
+    let wallet = &data.wallets[wallet_idx];
+    // Private key wallets keep their key in the seed field.
+    let kind = if wallet.mnemonic.is_empty() {
+        ShareKind::PRIVATE_KEY
+    } else {
+        ShareKind::SEED
+    };
+    let shares = match split_secret(&wallet.seed, kind, threshold, shares) {
+        Ok(shares) => shares,
+        Err(e) => {
+            s.add_layer(Dialog::info(StyledString::styled(
+                format!("Failed to split the wallet. Error: {}", e),
+                RED,
+            )));
+            return;
+        }
+    };
+    let mut content = StyledString::styled(
+        format!(
+            "Any {} of these {} shares restore the wallet, while fewer reveal nothing about it. Keep them in separate places.",
+            threshold,
+            shares.len()
+        ),
+        colour,
+    );
+    let mut all = vec![];
+    for share in &shares {
+        let words = share.to_words();
+        content.append(StyledString::styled(
+            format!("\n\nShare {} of {}\n", share.index, shares.len()),
+            colour,
+        ));
+        content.append(StyledString::styled(&words, OFF_WHITE));
+        all.push(words);
+    }
+    let all = all.join("\n");
+    s.add_layer(
+        Dialog::around(TextView::new(content).scrollable())
+            .button("Copy all", move |s| {
+                s.pop_layer();
+                s.pop_layer();
+                copy_to_clip(s, all.clone())
+            })
+            .button("Back", go_back)
+            .title("Shamir shares")
+            .max_width(80)
+            .max_height(30),
+    );
+}
+
+pub fn show_from_shares(s: &mut Cursive, name: String) {
+    s.pop_layer();
+    s.add_layer(
+        Dialog::new()
+            .title("Enter your shares, one per line")
+            .padding_lrtb(1, 1, 1, 0)
+            .content(
+                TextArea::new()
+                    .with_name("shares")
+                    .min_height(6)
+                    .fixed_width(70),
+            )
+            .h_align(HAlign::Center)
+            .button("Done", move |s| {
+                let shares = s
+                    .call_on_name("shares", |view: &mut TextArea| {
+                        String::from(view.get_content())
+                    })
+                    .unwrap();
+                process_from_shares(s, &shares, name.clone());
+            })
+            .button("Paste", |s| {
+                let mut shares: ViewRef<TextArea> = s.find_name("shares").unwrap();
+                shares.set_content(paste_clip(s));
+            })
+            .button("Back", show_wallets),
+    );
+}
+
+fn process_from_shares(s: &mut Cursive, content: &str, name: String) {
+    let mut shares = vec![];
+    for (i, line) in content.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        match Share::from_words(line) {
+            Ok(share) => shares.push(share),
+            Err(e) => {
+                s.add_layer(Dialog::info(format!("Share on line {}: {}.", i + 1, e)));
+                return;
+            }
+        }
+    }
+    let secret = match combine_shares(&shares) {
+        Ok(secret) => secret,
+        Err(e) => {
+            s.add_layer(Dialog::info(format!(
+                "Failed to restore the wallet: {}.",
+                e
+            )));
+            return;
+        }
+    };
+    let data = &s.user_data::<UserData>().unwrap();
+    let coin = &data.coins[data.coin_idx];
+    let colour = coin.colour();
+    let wallet = match shares[0].kind {
+        ShareKind::SEED => Wallet::new(seed_to_mnemonic(&secret), secret, name, &coin.prefix),
+        ShareKind::PRIVATE_KEY => Wallet::new_key(secret, name, &coin.prefix),
+        // combine_shares rejects any other kind.
+        _ => unreachable!(),
+    };
+    let mut content = StyledString::styled("\nAccount 0 of the restored wallet is\n", colour);
+    content.append(StyledString::styled(&wallet.accounts[0].address, OFF_WHITE));
+    content.append(StyledString::styled(
+        "\n\nThis matches the wallet the shares were made from.",
+        colour,
+    ));
+    let wallet = std::cell::Cell::new(Some(wallet));
+    s.add_layer(
+        Dialog::around(TextView::new(content))
+            .button("Import", move |s| {
+                if let Some(wallet) = wallet.take() {
+                    s.pop_layer();
+                    setup_wallet(s, wallet, |s| {
+                        import_success(s, "Successfully restored wallet from shares.")
+                    });
+                }
+            })
+            .button("Back", go_back)
+            .title("Restored wallet")
+            .max_width(80),
+    );
+}
@@ -0,0 +1,198 @@
+use super::super::structs::Wallet;
+use super::add::{import_success, setup_wallet};
+use crate::app::coin::Colour;
+use crate::app::components::work::{
+    precompute::cancel_precompute,
+    progress::{format_count, format_hashrate},
+};
+use crate::app::{
+    constants::colours::{OFF_WHITE, RED},
+    helpers::go_back,
+    themes::get_subtitle_colour,
+    userdata::UserData,
+};
+use cursive::traits::{Nameable, Resizable};
+use cursive::utils::markup::StyledString;
+use cursive::views::{
+    Dialog, DummyView, EditView, LinearLayout, ProgressBar, RadioGroup, TextView,
+};
+use cursive::Cursive;
+use dagchat_core::crypto::mnemonic::seed_to_mnemonic;
+use dagchat_core::crypto::pow::WorkControl;
+use dagchat_core::crypto::vanity::{find_vanity, VanityKind, VanityPattern};
+use dagchat_core::Error;
+use std::cell::Cell;
+
+pub fn show_vanity(s: &mut Cursive) {
+    let data = &s.user_data::<UserData>().unwrap();
+    let coin = &data.coins[data.coin_idx];
+    let colour = get_subtitle_colour(coin.colour());
+    let mut kind: RadioGroup<usize> = RadioGroup::new();
+    let content = LinearLayout::vertical()
+        .child(DummyView)
+        .child(TextView::new(StyledString::styled("Wallet name", colour)))
+        .child(
+            EditView::new()
+                .content(format!("Vanity {}", data.wallets.len() + 1))
+                .with_name("name"),
+        )
+        .child(DummyView)
+        .child(TextView::new(StyledString::styled(
+            format!("Characters after {}1", coin.prefix),
+            colour,
+        )))
+        .child(EditView::new().on_edit(show_difficulty).with_name("prefix"))
+        .child(DummyView)
+        .child(TextView::new(StyledString::styled(
+            "Characters at the end of the address",
+            colour,
+        )))
+        .child(EditView::new().on_edit(show_difficulty).with_name("suffix"))
+        .child(TextView::new("").with_name("difficulty"))
+        .child(DummyView)
+        .child(TextView::new(StyledString::styled("Generate a", colour)))
+        .child(kind.button(VanityKind::SEED, "Seed (with a mnemonic backup)"))
+        .child(kind.button(VanityKind::PRIVATE_KEY, "Private key (slightly faster)"));
+    s.add_layer(
+        Dialog::around(content)
+            .button("Search", move |s| {
+                let kind = *kind.selection();
+                start_search(s, kind);
+            })
+            .button("Back", go_back)
+            .title("Vanity address")
+            .max_width(80),
+    );
+}
+
+fn get_pattern(s: &mut Cursive) -> (String, String) {
+    let prefix = s
+        .call_on_name("prefix", |view: &mut EditView| view.get_content())
+        .unwrap();
+    let suffix = s
+        .call_on_name("suffix", |view: &mut EditView| view.get_content())
+        .unwrap();
+    (prefix.to_string(), suffix.to_string())
+}
+
+fn show_difficulty(s: &mut Cursive, _content: &str, _cursor: usize) {
+    let (prefix, suffix) = get_pattern(s);
+    let hint = if prefix.is_empty() && suffix.is_empty() {
+        String::new()
+    } else {
+        match VanityPattern::new(&prefix, &suffix) {
+            Ok(pattern) => format!(
+                "About {} addresses to try on average",
+                format_count(pattern.expected_attempts())
+            ),
+            Err(e) => format!("{}", e),
+        }
+    };
+    s.call_on_name("difficulty", |view: &mut TextView| view.set_content(hint));
+}
+
+fn start_search(s: &mut Cursive, kind: usize) {
+    cancel_precompute(s);
+    let (prefix, suffix) = get_pattern(s);
+    let pattern = match VanityPattern::new(&prefix, &suffix) {
+        Ok(pattern) => pattern,
+        Err(e) => {
+            s.add_layer(Dialog::info(format!("Invalid pattern: {}.", e)));
+            return;
+        }
+    };
+    let name = s
+        .call_on_name("name", |view: &mut EditView| view.get_content())
+        .unwrap()
+        .to_string();
+    let ticks = 1000;
+    let cb = s.cb_sink().clone();
+    let data = &s.user_data::<UserData>().unwrap();
+    let coin_name = data.coins[data.coin_idx].name.clone();
+    let control = WorkControl::default();
+    let cancel = control.clone();
+    let label_control = control.clone();
+    let expected = format!(
+        "Expecting to try about {} addresses. The search can take any amount of time, as every address is as likely to match as the last.",
+        format_count(pattern.expected_attempts())
+    );
+    s.add_layer(
+        Dialog::around(
+            LinearLayout::vertical()
+                .child(TextView::new(expected))
+                .child(DummyView)
+                .child(
+                    ProgressBar::new()
+                        .range(0, ticks)
+                        .with_label(move |value, (_, max)| {
+                            // The bar is the chance of having found a match by now.
+                            let chance =
+                                format!("{:.1} % likely", 100.0 * value as f64 / max as f64);
+                            let hashrate = label_control.hashrate();
+                            if hashrate == 0 {
+                                chance
+                            } else {
+                                format!("{} ({})", chance, format_hashrate(hashrate))
+                            }
+                        })
+                        .with_task(move |counter| {
+                            let result = find_vanity(&pattern, kind, &control, &|attempts| {
+                                counter.set((pattern.probability(attempts) * ticks as f64) as usize)
+                            });
+                            cb.send(Box::new(move |s| {
+                                s.set_autorefresh(false);
+                                s.pop_layer();
+                                match result {
+                                    Ok(secret) => show_found(s, secret, kind, name),
+                                    Err(Error::Cancelled) => {
+                                        s.add_layer(Dialog::info("Search cancelled."))
+                                    }
+                                    Err(e) => s.add_layer(Dialog::info(StyledString::styled(
+                                        format!("Failed to search for an address. Error: {}", e),
+                                        RED,
+                                    ))),
+                                }
+                            }))
+                            .unwrap();
+                        })
+                        .full_width(),
+                ),
+        )
+        .title(format!("Searching {} addresses", coin_name))
+        .button("Cancel", move |_| cancel.cancel())
+        .max_width(80),
+    );
+    s.set_autorefresh(true);
+}
+
+fn show_found(s: &mut Cursive, secret: [u8; 32], kind: usize, name: String) {
+    let data = &s.user_data::<UserData>().unwrap();
+    let coin = &data.coins[data.coin_idx];
+    let colour = coin.colour();
+    let wallet = if kind == VanityKind::SEED {
+        Wallet::new(seed_to_mnemonic(&secret), secret, name, &coin.prefix)
+    } else {
+        Wallet::new_key(secret, name, &coin.prefix)
+    };
+    let mut content = StyledString::styled("\nFound\n", colour);
+    content.append(StyledString::styled(&wallet.accounts[0].address, OFF_WHITE));
+    content.append(StyledString::styled(
+        "\n\nImport it as a new wallet, then back it up from the wallets screen.",
+        colour,
+    ));
+    let wallet = Cell::new(Some(wallet));
+    s.add_layer(
+        Dialog::around(TextView::new(content))
+            .button("Import", move |s| {
+                if let Some(wallet) = wallet.take() {
+                    s.pop_layer();
+                    setup_wallet(s, wallet, |s| {
+                        import_success(s, "Successfully imported vanity wallet.")
+                    });
+                }
+            })
+            .button("Discard", go_back)
+            .title("Vanity address")
+            .max_width(80),
+    );
+}
@@ -0,0 +1,2 @@
+pub mod precompute;
+pub mod progress;
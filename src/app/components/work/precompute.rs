@@ -0,0 +1,57 @@
+use crate::app::components::storage::save::save_to_storage;
+use crate::app::constants::colours::RED;
+use crate::app::userdata::UserData;
+use cursive::utils::markup::StyledString;
+use cursive::views::Dialog;
+use cursive::Cursive;
+use dagchat_core::crypto::pow::WorkControl;
+use std::thread;
+
+// Generates work for the next block of each address in the background,
+// so that it is ready before the user next sends, receives or messages.
+// Any earlier run is cancelled first, so only one is ever working.
+pub fn precompute_work(s: &mut Cursive, addresses: Vec<String>) {
+    cancel_precompute(s);
+    let cb = s.cb_sink().clone();
+    let data = &mut s.user_data::<UserData>().unwrap();
+    let coin = data.coins[data.coin_idx].clone();
+    let control = WorkControl::default();
+    data.precompute = Some(control.clone());
+    thread::spawn(move || {
+        let mut generated = false;
+        for address in addresses {
+            if control.is_cancelled() {
+                break;
+            }
+            if let Ok(true) = coin
+                .work_cache
+                .precompute(&address, &coin.network, &control)
+            {
+                generated = true;
+            }
+        }
+        if !generated {
+            return;
+        }
+        // The cache is shared with the coin in UserData, so saving
+        // storage is all that's left to persist the new work.
+        cb.send(Box::new(|s| {
+            if let Err(e) = save_to_storage(s) {
+                s.add_layer(
+                    Dialog::info(StyledString::styled(e, RED))
+                        .title("Failed to save precomputed work"),
+                );
+            }
+        }))
+        .unwrap();
+    });
+}
+
+// Stops background work so that it doesn't compete with work the user
+// is waiting on.
+pub fn cancel_precompute(s: &mut Cursive) {
+    let data = &mut s.user_data::<UserData>().unwrap();
+    if let Some(control) = data.precompute.take() {
+        control.cancel();
+    }
+}
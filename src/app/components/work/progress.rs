@@ -0,0 +1,41 @@
+use dagchat_core::crypto::pow::WorkControl;
+
+// Label for a ProgressBar that shows the local hashrate alongside the
+// percentage while work is being generated on this device.
+pub fn work_label(control: WorkControl) -> impl Fn(usize, (usize, usize)) -> String {
+    move |value, (min, max)| {
+        let percentage = if max > min {
+            100 * (value - min) / (max - min)
+        } else {
+            0
+        };
+        let hashrate = control.hashrate();
+        if hashrate == 0 {
+            format!("{} %", percentage)
+        } else {
+            format!("{} % ({})", percentage, format_hashrate(hashrate))
+        }
+    }
+}
+
+pub fn format_hashrate(hashrate: u64) -> String {
+    if hashrate >= 1_000_000 {
+        format!("{:.2} MH/s", hashrate as f64 / 1_000_000.0)
+    } else if hashrate >= 1_000 {
+        format!("{:.2} kH/s", hashrate as f64 / 1_000.0)
+    } else {
+        format!("{} H/s", hashrate)
+    }
+}
+
+pub fn format_count(count: f64) -> String {
+    if count >= 1e12 {
+        format!("{:.1} trillion", count / 1e12)
+    } else if count >= 1e9 {
+        format!("{:.1} billion", count / 1e9)
+    } else if count >= 1e6 {
+        format!("{:.1} million", count / 1e6)
+    } else {
+        format!("{:.0}", count)
+    }
+}
@@ -3,5 +3,6 @@ pub mod coin;
 pub mod components;
 pub mod constants;
 mod helpers;
+mod qr;
 mod themes;
 mod userdata;
@@ -0,0 +1,74 @@
+use super::constants::colours::RED;
+use super::helpers::go_back;
+use cursive::theme::{Color, ColorStyle};
+use cursive::traits::Resizable;
+use cursive::utils::markup::StyledString;
+use cursive::views::{Dialog, TextView};
+use cursive::{align::HAlign, Cursive};
+use qrcode::QrCode;
+
+// Light modules around the code, so it can be told apart from the
+// rest of the screen.
+const QUIET_ZONE: i32 = 2;
+
+// Draws two rows of modules per line with half blocks. The code is
+// always black on white, since phones can't read one inverted by a
+// dark terminal theme.
+pub fn qr_code(data: &str) -> Option<StyledString> {
+    let code = QrCode::new(data.as_bytes()).ok()?;
+    let width = code.width() as i32;
+    let dark = |x: i32, y: i32| {
+        (0..width).contains(&x)
+            && (0..width).contains(&y)
+            && code[(x as usize, y as usize)] == qrcode::Color::Dark
+    };
+    let mut lines = vec![];
+    let mut y = -QUIET_ZONE;
+    while y < width + QUIET_ZONE {
+        let line: String = (-QUIET_ZONE..width + QUIET_ZONE)
+            .map(|x| match (dark(x, y), dark(x, y + 1)) {
+                (true, true) => '█',
+                (true, false) => '▀',
+                (false, true) => '▄',
+                (false, false) => ' ',
+            })
+            .collect();
+        lines.push(line);
+        y += 2;
+    }
+    let style = ColorStyle::new(Color::Rgb(0, 0, 0), Color::Rgb(255, 255, 255));
+    Some(StyledString::styled(lines.join("\n"), style))
+}
+
+pub fn show_qr(s: &mut Cursive, title: &str, data: &str) {
+    match qr_code(data) {
+        Some(code) => s.add_layer(
+            Dialog::around(TextView::new(code).h_align(HAlign::Center))
+                .button("Back", go_back)
+                .title(title),
+        ),
+        None => s.add_layer(Dialog::info(StyledString::styled(
+            "This is too long to show as a QR code.",
+            RED,
+        ))),
+    }
+}
+
+// For secrets: anyone who can see the screen, or a camera pointed at
+// it, can take the wallet.
+pub fn show_secret_qr(s: &mut Cursive, title: &str, data: String) {
+    let title = String::from(title);
+    s.add_layer(
+        Dialog::text(StyledString::styled(
+            "Anyone who scans this QR code, or sees a photo or recording of your screen, can take everything in the wallet. Only show it to scan into a device you trust, with no one else watching.",
+            RED,
+        ))
+        .button("Show", move |s| {
+            s.pop_layer();
+            show_qr(s, &title, &data);
+        })
+        .button("Back", go_back)
+        .title("Warning")
+        .max_width(60),
+    );
+}
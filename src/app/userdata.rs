@@ -2,6 +2,10 @@ use super::coin::*;
 use super::components::wallets::structs::Wallet;
 use super::constants::{AUTHOR, AUTHOR_ADDR};
 use arboard::Clipboard;
+use dagchat_core::coin::Coin;
+use dagchat_core::crypto::pow::WorkControl;
+use dagchat_core::messages::envelope::Attachment;
+use dagchat_core::messages::send::MessageOptions;
 use std::collections::HashMap;
 
 pub struct UserData {
@@ -14,6 +18,17 @@ pub struct UserData {
     pub coins: Vec<Coin>,
     pub coin_idx: usize,
     pub encrypted_bytes: Vec<u8>,
+    // The file attached to the message being written, if any.
+    pub attachment: Option<Attachment>,
+    // Whether sent messages include a key for this wallet, so they can
+    // be read back from the chain.
+    pub sender_copies: bool,
+    // Messages are padded to a multiple of this many blocks.
+    pub bucket_blocks: usize,
+    // Whether messages are padded to a random length within the bucket.
+    pub random_padding: bool,
+    // Controls the background work generation, while it's running.
+    pub precompute: Option<WorkControl>,
 }
 
 impl UserData {
@@ -30,6 +45,19 @@ impl UserData {
             coins: vec![Coin::nano(), Coin::banano()],
             coin_idx: Coins::NANO,
             encrypted_bytes: vec![],
+            attachment: None,
+            sender_copies: false,
+            bucket_blocks: 1,
+            random_padding: false,
+            precompute: None,
+        }
+    }
+
+    pub fn message_options(&self) -> MessageOptions {
+        MessageOptions {
+            sender_copy: self.sender_copies,
+            bucket_blocks: self.bucket_blocks,
+            random_padding: self.random_padding,
         }
     }
 }
@@ -1,6 +1,4 @@
 mod app;
-mod crypto;
-mod rpc;
 
 use crate::app::components::title::ui::primary::*;
 use crate::app::constants::VERSION;